@@ -0,0 +1,546 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::BTreeSet;
+use std::hint::black_box;
+use std::io;
+use std::sync::atomic::{self, AtomicUsize};
+use std::time::{Duration, Instant};
+
+use crate::rng::Lcg;
+use crate::{DLList, DLListDrop, DropLink};
+
+/*Benchmarks
+
+Aufruf (immer im Release-Modus, sonst sind die Zeiten nicht aussagekräftig):
+    cargo run --release -- bench-pool
+    cargo run --release -- bench [--csv ergebnis.csv]
+
+bench-pool vergleicht DLList mit und ohne Node Pool (siehe pool.rs).
+
+bench vergleicht DLList, DLListDrop, einen sortierten Vec und BTreeSet für verschiedene Größen:
+    push (zufällige, aufsteigende und absteigende Eingabe), contains, pop_front, pop_back,
+    Iteration und drop
+Das Ergebnis wird als Tabelle ausgegeben (Zeit in µs), mit --csv zusätzlich als CSV-Datei.
+Die Eingabe ist eine Permutation von 0..n, damit BTreeSet keine Duplikate verwirft.
+*/
+
+//Zählt die Allokationen des aktuellen Threads, damit bench-pool echte Zahlen statt einer
+//Schätzung ausgibt. Wirkt nur, wenn er als #[global_allocator] eingetragen ist (siehe main.rs),
+//und zählt nur innerhalb von count_allocations. Sonst kostet jede Allokation nur ein Laden von ACTIVE.
+pub struct CountingAllocator;
+
+//Anzahl der gerade laufenden Messungen (über alle Threads)
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    //const und ohne Drop, damit der Zugriff selbst nichts allokiert
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn count_allocation() {
+    if ACTIVE.load(atomic::Ordering::Relaxed) == 0 {
+        return;
+    }
+    //try_with, weil beim Beenden eines Threads noch allokiert werden kann
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+//Führt f aus und gibt zusätzlich die Allokationen (alloc, alloc_zeroed, realloc) zurück,
+//die der aktuelle Thread dabei gemacht hat
+fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    ACTIVE.fetch_add(1, atomic::Ordering::Relaxed);
+    let result = f();
+    ACTIVE.fetch_sub(1, atomic::Ordering::Relaxed);
+
+    (result, ALLOCATIONS.with(Cell::get) - before)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count_allocation();
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count_allocation();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+struct SteadyState {
+    time: Duration,
+    //Allokationen während der gemessenen Schleife
+    allocations: usize,
+    list: DLList<u32>,
+}
+
+//Gleichmäßiger Betrieb: Liste mit size Werten, danach ops mal pop_front + push im Wechsel
+fn steady_state(mut list: DLList<u32>, size: usize, ops: usize) -> SteadyState {
+    let mut rng = Lcg(42);
+
+    for _ in 0..size {
+        list.push(rng.next());
+    }
+
+    let (time, allocations) = count_allocations(|| {
+        let start = Instant::now();
+        for _ in 0..ops {
+            list.pop_front();
+            list.push(rng.next());
+        }
+        start.elapsed()
+    });
+
+    SteadyState {
+        time,
+        allocations,
+        list,
+    }
+}
+
+pub fn pool_benchmark() {
+    let size = 64;
+    let ops = 1_000_000;
+
+    let without = steady_state(DLList::new(), size, ops);
+    let with = steady_state(DLList::with_capacity(size), size, ops);
+    let stats = with.list.pool_stats();
+
+    println!("Steady State: {} Werte, {} x pop_front + push", size, ops);
+    println!();
+    println!(
+        "{:<12} {:>12} {:>14}",
+        "Variante", "Zeit [ms]", "Allokationen"
+    );
+    println!(
+        "{:<12} {:>12.2} {:>14}",
+        "ohne Pool",
+        without.time.as_secs_f64() * 1000.0,
+        without.allocations
+    );
+    println!(
+        "{:<12} {:>12.2} {:>14}",
+        "mit Pool",
+        with.time.as_secs_f64() * 1000.0,
+        with.allocations
+    );
+    println!();
+    println!(
+        "Pool: {} Treffer, {} neu angelegt, {} zurückgelegt",
+        stats.hits, stats.misses, stats.recycled
+    );
+}
+
+const SIZES: [usize; 3] = [100, 1_000, 10_000];
+//contains wird höchstens so oft aufgerufen, bei den Listen ist jeder Aufruf O(n)
+const LOOKUPS: usize = 1_000;
+
+//Gemeinsame Schnittstelle der verglichenen Strukturen
+trait Subject {
+    const NAME: &'static str;
+
+    fn new() -> Self;
+    fn push(&mut self, wert: u32);
+    fn contains(&mut self, wert: &u32) -> bool;
+    fn pop_front(&mut self) -> Option<u32>;
+    fn pop_back(&mut self) -> Option<u32>;
+    //Einmal durch alle Werte laufen
+    fn sum(&self) -> u64;
+}
+
+impl Subject for DLList<u32> {
+    const NAME: &'static str = "DLList";
+
+    fn new() -> Self {
+        DLList::new()
+    }
+
+    fn push(&mut self, wert: u32) {
+        DLList::push(self, wert)
+    }
+
+    fn contains(&mut self, wert: &u32) -> bool {
+        DLList::contains(self, wert)
+    }
+
+    fn pop_front(&mut self) -> Option<u32> {
+        DLList::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<u32> {
+        DLList::pop_back(self)
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(u64::from).sum()
+    }
+}
+
+impl Subject for DLListDrop<u32> {
+    const NAME: &'static str = "DLListDrop";
+
+    fn new() -> Self {
+        DLListDrop::new()
+    }
+
+    fn push(&mut self, wert: u32) {
+        DLListDrop::push(self, wert)
+    }
+
+    fn contains(&mut self, wert: &u32) -> bool {
+        DLListDrop::contains(self, wert)
+    }
+
+    fn pop_front(&mut self) -> Option<u32> {
+        DLListDrop::pop_front(self)
+    }
+
+    fn pop_back(&mut self) -> Option<u32> {
+        DLListDrop::pop_back(self)
+    }
+
+    fn sum(&self) -> u64 {
+        //DLListDrop hat keinen Iterator, deshalb direkt über die Knoten laufen
+        let mut sum = 0;
+        let mut current: Option<DropLink<u32>> = self.head.clone();
+        while let Some(node) = current {
+            sum += u64::from(node.borrow().item);
+            current = node.borrow().next.clone();
+        }
+        sum
+    }
+}
+
+//Vec, der mit binärer Suche sortiert gehalten wird
+struct SortedVec(Vec<u32>);
+
+impl Subject for SortedVec {
+    const NAME: &'static str = "Vec";
+
+    fn new() -> Self {
+        SortedVec(Vec::new())
+    }
+
+    fn push(&mut self, wert: u32) {
+        let position = self.0.partition_point(|other| *other < wert);
+        self.0.insert(position, wert);
+    }
+
+    fn contains(&mut self, wert: &u32) -> bool {
+        self.0.binary_search(wert).is_ok()
+    }
+
+    fn pop_front(&mut self) -> Option<u32> {
+        if self.0.is_empty() {
+            return None;
+        }
+        Some(self.0.remove(0))
+    }
+
+    fn pop_back(&mut self) -> Option<u32> {
+        self.0.pop()
+    }
+
+    fn sum(&self) -> u64 {
+        self.0.iter().copied().map(u64::from).sum()
+    }
+}
+
+impl Subject for BTreeSet<u32> {
+    const NAME: &'static str = "BTreeSet";
+
+    fn new() -> Self {
+        BTreeSet::new()
+    }
+
+    fn push(&mut self, wert: u32) {
+        self.insert(wert);
+    }
+
+    fn contains(&mut self, wert: &u32) -> bool {
+        BTreeSet::contains(self, wert)
+    }
+
+    fn pop_front(&mut self) -> Option<u32> {
+        self.pop_first()
+    }
+
+    fn pop_back(&mut self) -> Option<u32> {
+        self.pop_last()
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().copied().map(u64::from).sum()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Operation {
+    PushRandom,
+    PushAscending,
+    PushDescending,
+    Contains,
+    PopFront,
+    PopBack,
+    Iterate,
+    Drop,
+}
+
+impl Operation {
+    const ALL: [Operation; 8] = [
+        Operation::PushRandom,
+        Operation::PushAscending,
+        Operation::PushDescending,
+        Operation::Contains,
+        Operation::PopFront,
+        Operation::PopBack,
+        Operation::Iterate,
+        Operation::Drop,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Operation::PushRandom => "push zufällig",
+            Operation::PushAscending => "push aufsteigend",
+            Operation::PushDescending => "push absteigend",
+            Operation::Contains => "contains",
+            Operation::PopFront => "pop_front",
+            Operation::PopBack => "pop_back",
+            Operation::Iterate => "iter",
+            Operation::Drop => "drop",
+        }
+    }
+}
+
+struct Measurement {
+    operation: Operation,
+    size: usize,
+    structure: &'static str,
+    time: Duration,
+}
+
+//Zufällige Reihenfolge von 0..size (Fisher-Yates mit dem LCG)
+fn shuffled(size: usize, rng: &mut Lcg) -> Vec<u32> {
+    let mut values: Vec<u32> = (0..size as u32).collect();
+    for i in (1..values.len()).rev() {
+        let j = rng.next() as usize % (i + 1);
+        values.swap(i, j);
+    }
+    values
+}
+
+fn build<S: Subject>(values: &[u32]) -> (Duration, S) {
+    let mut subject = S::new();
+    let start = Instant::now();
+    for wert in values {
+        subject.push(*wert);
+    }
+    (start.elapsed(), subject)
+}
+
+fn time<R>(f: impl FnOnce() -> R) -> Duration {
+    let start = Instant::now();
+    black_box(f());
+    start.elapsed()
+}
+
+//Alle Operationen für eine Struktur und eine Größe messen
+fn measure<S: Subject>(size: usize) -> Vec<Measurement> {
+    let mut rng = Lcg(7);
+    let random = shuffled(size, &mut rng);
+    let ascending: Vec<u32> = (0..size as u32).collect();
+    let descending: Vec<u32> = ascending.iter().rev().copied().collect();
+    //Etwa die Hälfte der gesuchten Werte ist nicht in der Struktur
+    let lookups: Vec<u32> = (0..size.min(LOOKUPS))
+        .map(|_| rng.next() % (2 * size as u32))
+        .collect();
+
+    let mut results = Vec::new();
+    let mut record = |operation, time| {
+        results.push(Measurement {
+            operation,
+            size,
+            structure: S::NAME,
+            time,
+        })
+    };
+
+    let (push_random, mut subject) = build::<S>(&random);
+    record(Operation::PushRandom, push_random);
+    record(Operation::PushAscending, build::<S>(&ascending).0);
+    record(Operation::PushDescending, build::<S>(&descending).0);
+
+    record(
+        Operation::Contains,
+        time(|| lookups.iter().filter(|wert| subject.contains(wert)).count()),
+    );
+    record(Operation::Iterate, time(|| subject.sum()));
+    record(Operation::Drop, time(|| drop(subject)));
+
+    let (_, mut subject) = build::<S>(&random);
+    record(
+        Operation::PopFront,
+        time(|| while subject.pop_front().is_some() {}),
+    );
+
+    let (_, mut subject) = build::<S>(&random);
+    record(
+        Operation::PopBack,
+        time(|| while subject.pop_back().is_some() {}),
+    );
+
+    results
+}
+
+fn run(sizes: &[usize]) -> Vec<Measurement> {
+    let mut results = Vec::new();
+    for &size in sizes {
+        results.extend(measure::<DLList<u32>>(size));
+        results.extend(measure::<DLListDrop<u32>>(size));
+        results.extend(measure::<SortedVec>(size));
+        results.extend(measure::<BTreeSet<u32>>(size));
+    }
+    results
+}
+
+const STRUCTURES: [&str; 4] = [
+    <DLList<u32> as Subject>::NAME,
+    <DLListDrop<u32> as Subject>::NAME,
+    SortedVec::NAME,
+    <BTreeSet<u32> as Subject>::NAME,
+];
+
+fn print_table(results: &[Measurement], sizes: &[usize]) {
+    print!("{:<18} {:>8}", "Operation", "n");
+    for name in STRUCTURES {
+        print!(" {:>12}", name);
+    }
+    println!();
+
+    for operation in Operation::ALL {
+        for &size in sizes {
+            print!("{:<18} {:>8}", operation.label(), size);
+            for name in STRUCTURES {
+                let found = results
+                    .iter()
+                    .find(|m| m.operation == operation && m.size == size && m.structure == name);
+                match found {
+                    Some(m) => print!(" {:>12.1}", m.time.as_secs_f64() * 1e6),
+                    None => print!(" {:>12}", "-"),
+                }
+            }
+            println!();
+        }
+    }
+}
+
+fn to_csv(results: &[Measurement]) -> String {
+    let mut csv = String::from("operation,size,structure,micros\n");
+    for m in results {
+        csv.push_str(&format!(
+            "{},{},{},{:.3}\n",
+            m.operation.label(),
+            m.size,
+            m.structure,
+            m.time.as_secs_f64() * 1e6
+        ));
+    }
+    csv
+}
+
+pub fn comparison_benchmark(csv_path: Option<&str>) -> io::Result<()> {
+    let results = run(&SIZES);
+
+    println!("Zeit in µs, Eingabe ist eine Permutation von 0..n");
+    println!();
+    print_table(&results, &SIZES);
+
+    if let Some(path) = csv_path {
+        std::fs::write(path, to_csv(&results))?;
+        println!();
+        println!("CSV geschrieben: {}", path);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn pool_avoids_allocations() {
+        let without = steady_state(DLList::new(), 16, 200);
+        let with = steady_state(DLList::with_capacity(16), 16, 200);
+
+        //Ohne Pool braucht jedes push einen neuen Knoten
+        assert!(without.allocations >= 200);
+        assert_eq!(with.allocations, 0);
+        assert_eq!(with.list.pool_stats().misses, 0);
+    }
+
+    #[test]
+    fn every_operation_is_measured() {
+        let results = run(&[20]);
+        assert_eq!(results.len(), Operation::ALL.len() * STRUCTURES.len());
+
+        for name in STRUCTURES {
+            for operation in Operation::ALL {
+                assert!(
+                    results
+                        .iter()
+                        .any(|m| m.structure == name && m.operation == operation)
+                );
+            }
+        }
+    }
+
+    //Alle Strukturen müssen sich gleich verhalten, sonst vergleicht der Benchmark Unterschiedliches
+    fn check_subject<S: Subject>() {
+        let mut rng = Lcg(3);
+        let values = shuffled(50, &mut rng);
+        let (_, mut subject) = build::<S>(&values);
+
+        assert_eq!(subject.sum(), (0..50).sum::<u64>());
+        assert!(subject.contains(&49));
+        assert!(!subject.contains(&50));
+        assert_eq!(subject.pop_front(), Some(0));
+        assert_eq!(subject.pop_back(), Some(49));
+    }
+
+    #[test]
+    fn subjects_agree() {
+        check_subject::<DLList<u32>>();
+        check_subject::<DLListDrop<u32>>();
+        check_subject::<SortedVec>();
+        check_subject::<BTreeSet<u32>>();
+    }
+
+    #[test]
+    fn csv_output() {
+        let results = vec![Measurement {
+            operation: Operation::PopBack,
+            size: 10,
+            structure: "Vec",
+            time: Duration::from_micros(5),
+        }];
+
+        assert_eq!(
+            to_csv(&results),
+            "operation,size,structure,micros\npop_back,10,Vec,5.000\n"
+        );
+    }
+}
@@ -0,0 +1,194 @@
+use alloc::vec::Vec;
+use core::borrow;
+
+use crate::{DLList, Iter};
+
+/*Begrenzte sortierte Liste (Top-K / Bestenliste)
+
+Die Liste hält höchstens capacity Werte. Ist sie voll, wird bei einem push ein Wert verdrängt:
+    Eviction::Smallest -> der kleinste Wert fliegt raus (die Liste hält die K größten Werte)
+    Eviction::Largest  -> der größte Wert fliegt raus  (die Liste hält die K kleinsten Werte)
+
+Da die Liste sortiert ist, liegt der Kandidat zum Verdrängen immer bei head bzw. tail.
+Ein neuer Wert, der sofort wieder verdrängt würde, wird deshalb in O(1) durch einen Vergleich
+mit head/tail abgelehnt, ohne die Liste zu durchlaufen.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Eviction {
+    Smallest,
+    Largest,
+}
+
+pub struct BoundedList<T> {
+    list: DLList<T>,
+    len: usize,
+    capacity: usize,
+    eviction: Eviction,
+}
+
+impl<T: Ord> BoundedList<T> {
+    pub fn new(capacity: usize, eviction: Eviction) -> Self {
+        Self {
+            list: DLList::new(),
+            len: 0,
+            capacity,
+            eviction,
+        }
+    }
+
+    //Fügt den Wert ein und gibt den verdrängten Wert zurück (kann auch wert selbst sein)
+    pub fn push(&mut self, wert: T) -> Option<T> {
+        if self.len < self.capacity {
+            self.list.push(wert);
+            self.len += 1;
+            return None;
+        }
+
+        //Liste ist voll (oder capacity ist 0): Würde wert sofort wieder verdrängt werden?
+        if self.would_evict(&wert) {
+            return Some(wert);
+        }
+
+        self.list.push(wert);
+        match self.eviction {
+            Eviction::Smallest => self.list.pop_front(),
+            Eviction::Largest => self.list.pop_back(),
+        }
+    }
+
+    //Vergleich mit dem Wert, der als nächstes verdrängt wird (O(1))
+    fn would_evict(&self, wert: &T) -> bool {
+        match self.eviction {
+            Eviction::Smallest => match &self.list.head {
+                Some(head) => *wert <= head.borrow().item,
+                None => true,
+            },
+            Eviction::Largest => match &self.list.tail {
+                Some(tail) => *wert >= tail.borrow().item,
+                None => true,
+            },
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let wert = self.list.pop_front()?;
+        self.len -= 1;
+        Some(wert)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let wert = self.list.pop_back()?;
+        self.len -= 1;
+        Some(wert)
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let wert = self.list.remove(element)?;
+        self.len -= 1;
+        Some(wert)
+    }
+
+    pub fn contains<Q: Ord + ?Sized>(&mut self, element: &Q) -> bool
+    where
+        T: borrow::Borrow<Q>,
+    {
+        self.list.contains(element)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_vec(&mut self) -> Vec<T> {
+        self.len = 0;
+        self.list.to_vec()
+    }
+}
+
+impl<T> BoundedList<T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len >= self.capacity
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn eviction(&self) -> Eviction {
+        self.eviction
+    }
+
+    pub fn iter(&self) -> Iter<T> {
+        self.list.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_largest_values() {
+        let mut best = BoundedList::new(3, Eviction::Smallest);
+
+        assert_eq!(best.push(5), None);
+        assert_eq!(best.push(1), None);
+        assert_eq!(best.push(9), None);
+        assert!(best.is_full());
+
+        assert_eq!(best.push(7), Some(1));
+        assert_eq!(best.push(2), Some(2));
+        assert_eq!(best.push(5), Some(5));
+        assert_eq!(best.push(10), Some(5));
+
+        assert_eq!(best.len(), 3);
+        assert_eq!(best.to_vec(), vec![7, 9, 10]);
+    }
+
+    #[test]
+    fn keep_smallest_values() {
+        let mut best = BoundedList::new(3, Eviction::Largest);
+
+        for ele in [8, 6, 17] {
+            assert_eq!(best.push(ele), None);
+        }
+
+        assert_eq!(best.push(35), Some(35));
+        assert_eq!(best.push(1), Some(17));
+        assert_eq!(best.push(0), Some(8));
+        assert_eq!(best.iter().collect::<Vec<_>>(), vec![0, 1, 6]);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut best = BoundedList::new(0, Eviction::Smallest);
+
+        assert_eq!(best.push(1), Some(1));
+        assert!(best.is_empty());
+        assert!(best.is_full());
+    }
+
+    #[test]
+    fn remove_makes_room_again() {
+        let mut best = BoundedList::new(2, Eviction::Smallest);
+        best.push(3);
+        best.push(4);
+
+        assert_eq!(best.remove(&4), Some(4));
+        assert_eq!(best.pop_back(), Some(3));
+        assert_eq!(best.pop_front(), None);
+
+        assert_eq!(best.push(1), None);
+        assert_eq!(best.push(2), None);
+        assert!(best.contains(&1));
+        assert_eq!(best.push(0), Some(0));
+    }
+}
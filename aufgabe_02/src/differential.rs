@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::rng::Lcg;
+use crate::{DLList, DLListDrop};
+
+/*Differentielles Testen mit Zufallsfolgen
+
+Die anderen Tests benutzen handverlesene Werte. Hier werden mit festem Startwert (Seed) zufällige
+Folgen von Operationen erzeugt und gleichzeitig auf die Liste und auf ein Referenzmodell angewendet.
+Das Modell ist eine BTreeMap<Wert, Anzahl>, die trivial richtig ist. Nach jeder Operation müssen
+das Ergebnis (z.B. der Rückgabewert von pop_front) und der gesamte Inhalt übereinstimmen.
+
+Schlägt eine Folge fehl, wird sie verkleinert (Shrinking):
+    1. Blöcke von Operationen weglassen (erst große, dann immer kleinere), solange es noch fehlschlägt
+    2. Werte verkleinern (z.B. Push(9) -> Push(0)), solange es noch fehlschlägt
+Die kleinste gefundene Folge wird als fertiger Testfall ausgegeben, den man hier einfügen kann.
+
+DLListDrop hat kein remove, deshalb werden für sie nur Folgen ohne Remove erzeugt.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Push(i32),
+    PopFront,
+    PopBack,
+    Contains(i32),
+    Remove(i32),
+}
+
+//Beobachtbares Ergebnis einer Operation
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Pushed,
+    Popped(Option<i32>),
+    Found(bool),
+    Removed(Option<i32>),
+}
+
+trait Subject {
+    fn new() -> Self;
+    fn apply(&mut self, op: Op) -> Outcome;
+    //Inhalt in sortierter Reihenfolge
+    fn values(&self) -> Vec<i32>;
+}
+
+//Referenzmodell
+struct Model(BTreeMap<i32, usize>);
+
+impl Model {
+    fn take(&mut self, wert: i32) -> i32 {
+        let count = self.0.get_mut(&wert).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.0.remove(&wert);
+        }
+        wert
+    }
+}
+
+impl Subject for Model {
+    fn new() -> Self {
+        Model(BTreeMap::new())
+    }
+
+    fn apply(&mut self, op: Op) -> Outcome {
+        match op {
+            Op::Push(wert) => {
+                *self.0.entry(wert).or_default() += 1;
+                Outcome::Pushed
+            }
+            Op::PopFront => {
+                let first = self.0.keys().next().copied();
+                Outcome::Popped(first.map(|wert| self.take(wert)))
+            }
+            Op::PopBack => {
+                let last = self.0.keys().next_back().copied();
+                Outcome::Popped(last.map(|wert| self.take(wert)))
+            }
+            Op::Contains(wert) => Outcome::Found(self.0.contains_key(&wert)),
+            Op::Remove(wert) => {
+                let found = self.0.contains_key(&wert);
+                Outcome::Removed(found.then(|| self.take(wert)))
+            }
+        }
+    }
+
+    fn values(&self) -> Vec<i32> {
+        self.0
+            .iter()
+            .flat_map(|(wert, count)| std::iter::repeat_n(*wert, *count))
+            .collect()
+    }
+}
+
+impl Subject for DLList<i32> {
+    fn new() -> Self {
+        DLList::new()
+    }
+
+    fn apply(&mut self, op: Op) -> Outcome {
+        match op {
+            Op::Push(wert) => {
+                self.push(wert);
+                Outcome::Pushed
+            }
+            Op::PopFront => Outcome::Popped(self.pop_front()),
+            Op::PopBack => Outcome::Popped(self.pop_back()),
+            Op::Contains(wert) => Outcome::Found(self.contains(&wert)),
+            Op::Remove(wert) => Outcome::Removed(self.remove(&wert)),
+        }
+    }
+
+    fn values(&self) -> Vec<i32> {
+        self.iter().collect()
+    }
+}
+
+impl Subject for DLListDrop<i32> {
+    fn new() -> Self {
+        DLListDrop::new()
+    }
+
+    fn apply(&mut self, op: Op) -> Outcome {
+        match op {
+            Op::Push(wert) => {
+                self.push(wert);
+                Outcome::Pushed
+            }
+            Op::PopFront => Outcome::Popped(self.pop_front()),
+            Op::PopBack => Outcome::Popped(self.pop_back()),
+            Op::Contains(wert) => Outcome::Found(self.contains(&wert)),
+            Op::Remove(_) => panic!("DLListDrop hat kein remove"),
+        }
+    }
+
+    fn values(&self) -> Vec<i32> {
+        let mut out = Vec::new();
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            out.push(node.borrow().item);
+            current = node.borrow().next.clone();
+        }
+        out
+    }
+}
+
+#[derive(Debug)]
+struct Failure {
+    //Index der Operation, bei der der Unterschied aufgefallen ist
+    step: usize,
+    message: String,
+}
+
+//Folge auf die Liste und das Modell anwenden und nach jedem Schritt vergleichen
+fn check<S: Subject>(ops: &[Op]) -> Result<(), Failure> {
+    let mut subject = S::new();
+    let mut model = Model::new();
+
+    for (step, op) in ops.iter().enumerate() {
+        let actual = subject.apply(*op);
+        let expected = model.apply(*op);
+        if actual != expected {
+            return Err(Failure {
+                step,
+                message: format!("{:?}: erwartet {:?}, bekommen {:?}", op, expected, actual),
+            });
+        }
+
+        let actual = subject.values();
+        let expected = model.values();
+        if actual != expected {
+            return Err(Failure {
+                step,
+                message: format!(
+                    "nach {:?}: Inhalt erwartet {:?}, bekommen {:?}",
+                    op, expected, actual
+                ),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+//Eine Panik in der Liste zählt auch als Fehler
+fn fails<S: Subject>(ops: &[Op]) -> bool {
+    catch_unwind(AssertUnwindSafe(|| check::<S>(ops).is_err())).unwrap_or(true)
+}
+
+//Kleiner Wertebereich, damit es Duplikate und Treffer bei contains/remove gibt
+const VALUES: u32 = 10;
+
+fn generate(seed: u64, len: usize, with_remove: bool) -> Vec<Op> {
+    let mut rng = Lcg(seed);
+
+    (0..len)
+        .map(|_| {
+            let wert = (rng.next() % VALUES) as i32;
+            match rng.next() % 20 {
+                0..=7 => Op::Push(wert),
+                8..=10 => Op::PopFront,
+                11..=13 => Op::PopBack,
+                14..=16 => Op::Contains(wert),
+                _ if with_remove => Op::Remove(wert),
+                _ => Op::Push(wert),
+            }
+        })
+        .collect()
+}
+
+fn shrink<S: Subject>(mut ops: Vec<Op>) -> Vec<Op> {
+    //Alles nach dem ersten Fehler wird nicht gebraucht
+    if let Ok(Err(failure)) = catch_unwind(AssertUnwindSafe(|| check::<S>(&ops))) {
+        ops.truncate(failure.step + 1);
+    }
+
+    //1. Blöcke weglassen
+    let mut chunk = ops.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < ops.len() {
+            let end = (start + chunk).min(ops.len());
+            let candidate: Vec<Op> = ops[..start].iter().chain(&ops[end..]).copied().collect();
+            if fails::<S>(&candidate) {
+                ops = candidate;
+            } else {
+                start += chunk;
+            }
+        }
+        chunk /= 2;
+    }
+
+    //2. Werte verkleinern
+    for i in 0..ops.len() {
+        for smaller in 0..VALUES as i32 {
+            let candidate_op = match ops[i] {
+                Op::Push(wert) if smaller < wert => Op::Push(smaller),
+                Op::Contains(wert) if smaller < wert => Op::Contains(smaller),
+                Op::Remove(wert) if smaller < wert => Op::Remove(smaller),
+                _ => break,
+            };
+            let mut candidate = ops.clone();
+            candidate[i] = candidate_op;
+            if fails::<S>(&candidate) {
+                ops = candidate;
+                break;
+            }
+        }
+    }
+
+    ops
+}
+
+//Folge als Testfall zum Einfügen in dieses Modul
+fn test_case(name: &str, check_fn: &str, ops: &[Op]) -> String {
+    let mut out = format!("#[test]\nfn {}() {{\n    let ops = [\n", name);
+    for op in ops {
+        out.push_str(&format!("        Op::{:?},\n", op));
+    }
+    out.push_str(&format!("    ];\n    {}(&ops).unwrap();\n}}\n", check_fn));
+    out
+}
+
+fn run_seeds<S: Subject>(name: &str, check_fn: &str, seeds: u64, with_remove: bool) {
+    for seed in 0..seeds {
+        let ops = generate(seed, 100, with_remove);
+        if !fails::<S>(&ops) {
+            continue;
+        }
+
+        let minimal = shrink::<S>(ops);
+        let message = match catch_unwind(AssertUnwindSafe(|| check::<S>(&minimal))) {
+            Ok(Err(failure)) => failure.message,
+            _ => String::from("Panik in der Liste"),
+        };
+        panic!(
+            "{}: Seed {} weicht vom Modell ab ({}). Kleinste Folge:\n\n{}",
+            name,
+            seed,
+            message,
+            test_case(&format!("{}_seed_{}", name, seed), check_fn, &minimal)
+        );
+    }
+}
+
+fn check_dllist(ops: &[Op]) -> Result<(), Failure> {
+    check::<DLList<i32>>(ops)
+}
+
+fn check_dllist_drop(ops: &[Op]) -> Result<(), Failure> {
+    check::<DLListDrop<i32>>(ops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dllist_matches_model() {
+        run_seeds::<DLList<i32>>("dllist", "check_dllist", 300, true);
+    }
+
+    #[test]
+    fn dllist_drop_matches_model() {
+        run_seeds::<DLListDrop<i32>>("dllist_drop", "check_dllist_drop", 300, false);
+    }
+
+    #[test]
+    fn generator_is_deterministic() {
+        assert_eq!(generate(42, 50, true), generate(42, 50, true));
+        assert_ne!(generate(1, 50, true), generate(2, 50, true));
+        assert!(!generate(5, 200, false).contains(&Op::Remove(0)));
+    }
+
+    //Absichtlich fehlerhafte Liste: pop_back gibt ab drei Werten den kleinsten statt den größten zurück
+    struct Buggy(DLList<i32>, usize);
+
+    impl Subject for Buggy {
+        fn new() -> Self {
+            Buggy(DLList::new(), 0)
+        }
+
+        fn apply(&mut self, op: Op) -> Outcome {
+            let outcome = match op {
+                Op::PopBack if self.1 >= 3 => Outcome::Popped(self.0.pop_front()),
+                _ => self.0.apply(op),
+            };
+            match outcome {
+                Outcome::Pushed => self.1 += 1,
+                Outcome::Popped(Some(_)) | Outcome::Removed(Some(_)) => self.1 -= 1,
+                _ => {}
+            }
+            outcome
+        }
+
+        fn values(&self) -> Vec<i32> {
+            self.0.values()
+        }
+    }
+
+    #[test]
+    fn shrinks_to_minimal_sequence() {
+        let ops = (0..300)
+            .map(|seed| generate(seed, 100, true))
+            .find(|ops| fails::<Buggy>(ops))
+            .unwrap();
+
+        let minimal = shrink::<Buggy>(ops);
+        assert_eq!(
+            minimal,
+            vec![Op::Push(0), Op::Push(0), Op::Push(1), Op::PopBack]
+        );
+    }
+
+    #[test]
+    fn prints_test_case() {
+        let case = test_case("dllist_seed_3", "check_dllist", &[Op::Push(2), Op::PopBack]);
+
+        assert_eq!(
+            case,
+            "#[test]\nfn dllist_seed_3() {\n    let ops = [\n        Op::Push(2),\n        \
+             Op::PopBack,\n    ];\n    check_dllist(&ops).unwrap();\n}\n"
+        );
+        check_dllist(&[Op::Push(2), Op::PopBack]).unwrap();
+        check_dllist_drop(&[Op::Push(2), Op::PopBack]).unwrap();
+    }
+}
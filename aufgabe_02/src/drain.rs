@@ -0,0 +1,407 @@
+use alloc::rc::Rc;
+use core::borrow;
+use core::cmp::Ordering;
+use core::ops::{Bound, RangeBounds};
+
+use crate::observer::ChangeKind;
+use crate::{
+    DLList, DLListDrop, DropLink, Link, compare, get_next, get_prev, set_next, set_prev, to_weak,
+};
+
+/*drain und extract_if
+
+drain(range):
+    Entfernt alle Werte im Bereich (z.B. drain(..10) für alle Werte kleiner 10) auf einmal.
+    Da die Liste sortiert ist, liegen diese Werte direkt hintereinander. Es wird der erste und
+    letzte Knoten im Bereich gesucht und die ganze Teilkette mit wenigen Zeigeränderungen
+    ausgehängt:
+
+        a <-> [b <-> c <-> d] <-> e      =>      a <-> e      und      b <-> c <-> d
+
+    Die ausgehängte Kette wird als eigene Liste im Drain gehalten und beim Iterieren abgebaut.
+    Wird der Drain nicht ganz durchlaufen, sind die Werte trotzdem aus der Liste entfernt.
+
+drain_by(range):
+    Wie drain, der Bereich darf aber wie bei BTreeMap::range in geliehener Form angegeben
+    werden, z.B. drain_by::<str, _>(..) bei einer Liste aus String.
+
+extract_if(pred):
+    Läuft lazy durch die Liste und hängt jeden Knoten aus, für den pred true zurückgibt.
+    Die Werte können dabei nur gelesen werden (&T), da eine Änderung die Sortierung zerstören würde.
+*/
+
+//Liegt der Wert vor dem Anfang des Bereichs?
+fn before_start<T: borrow::Borrow<Q>, Q: Ord + ?Sized>(start: Bound<&Q>, item: &T) -> bool {
+    match start {
+        Bound::Included(start) => compare(item, start) == Ordering::Less,
+        Bound::Excluded(start) => compare(item, start) != Ordering::Greater,
+        Bound::Unbounded => false,
+    }
+}
+
+//Liegt der Wert hinter dem Ende des Bereichs?
+fn after_end<T: borrow::Borrow<Q>, Q: Ord + ?Sized>(end: Bound<&Q>, item: &T) -> bool {
+    match end {
+        Bound::Included(end) => compare(item, end) == Ordering::Greater,
+        Bound::Excluded(end) => compare(item, end) != Ordering::Less,
+        Bound::Unbounded => false,
+    }
+}
+
+pub struct Drain<T> {
+    list: DLList<T>,
+}
+
+impl<T: Ord> Iterator for Drain<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T: Ord> DoubleEndedIterator for Drain<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+pub struct ExtractIf<'a, T, F: FnMut(&T) -> bool> {
+    list: &'a mut DLList<T>,
+    next: Option<Link<T>>,
+    pred: F,
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for ExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(node) = self.next.take() {
+            self.next = get_next(&node);
+
+            let matches = (self.pred)(&node.borrow().item);
+            if matches {
+                self.list.unlink_node(&node);
+                return Some(self.list.pool.free(node));
+            }
+        }
+
+        None
+    }
+}
+
+impl<T: Ord> DLList<T> {
+    pub fn drain<R: RangeBounds<T>>(&mut self, range: R) -> Drain<T> {
+        self.drain_by::<T, R>(range)
+    }
+
+    //Wie drain, der Bereich kann aber wie bei BTreeMap::range in einer geliehenen Form
+    //angegeben werden (z.B. &str bei einer Liste aus String)
+    pub fn drain_by<Q: Ord + ?Sized, R: RangeBounds<Q>>(&mut self, range: R) -> Drain<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let mut drained = DLList::new();
+
+        //Ersten Knoten im Bereich suchen
+        let mut first = self.head.clone();
+        while let Some(ref node) = first {
+            if !before_start(range.start_bound(), &node.borrow().item) {
+                break;
+            }
+            first = get_next(node);
+        }
+
+        let first = match first {
+            Some(node) if !after_end(range.end_bound(), &node.borrow().item) => node,
+            //Kein Wert im Bereich
+            _ => return Drain { list: drained },
+        };
+
+        //Letzten Knoten im Bereich suchen
+        let mut last = first.clone();
+        while let Some(next) = get_next(&last) {
+            if after_end(range.end_bound(), &next.borrow().item) {
+                break;
+            }
+            last = next;
+        }
+
+        //Teilkette aushängen und die Nachbarn direkt verbinden
+        self.modified();
+        let before = get_prev(&first).and_then(|weak| weak.upgrade());
+        let after = get_next(&last);
+
+        match &before {
+            Some(node_before) => set_next(node_before, after.clone()),
+            None => self.head = after.clone(),
+        }
+        match &after {
+            Some(node_after) => set_prev(node_after, to_weak(&before)),
+            None => self.tail = before.clone(),
+        }
+
+        set_prev(&first, None);
+        set_next(&last, None);
+
+        //Beobachter so informieren, als würden die Werte von vorne einzeln entfernt
+        let mut current = Some(first.clone());
+        while let Some(node) = current {
+            current = get_next(&node);
+            let next = current.clone().or_else(|| after.clone());
+            self.observers
+                .notify(ChangeKind::Removed, &node, before.as_ref(), next.as_ref());
+        }
+
+        drained.head = Some(first);
+        drained.tail = Some(last);
+        Drain { list: drained }
+    }
+}
+
+impl<T> DLList<T> {
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, T, F> {
+        let next = self.head.clone();
+        ExtractIf {
+            list: self,
+            next,
+            pred,
+        }
+    }
+}
+
+/*Gleiche Funktionen für DLListDrop
+
+Hier sind auch die prev-Zeiger starke Referenzen. Beim Aushängen müssen deshalb beide
+Zeiger des Knotens gelöscht werden, sonst bleibt ein Zyklus bestehen.
+*/
+pub struct DropDrain<T> {
+    list: DLListDrop<T>,
+}
+
+impl<T: Ord> Iterator for DropDrain<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T: Ord> DoubleEndedIterator for DropDrain<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+pub struct DropExtractIf<'a, T, F: FnMut(&T) -> bool> {
+    list: &'a mut DLListDrop<T>,
+    next: Option<DropLink<T>>,
+    pred: F,
+}
+
+impl<T, F: FnMut(&T) -> bool> Iterator for DropExtractIf<'_, T, F> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(node) = self.next.take() {
+            self.next = node.borrow().next.clone();
+
+            let matches = (self.pred)(&node.borrow().item);
+            if matches {
+                let prev = node.borrow_mut().prev.take();
+                let next = node.borrow_mut().next.take();
+
+                match &prev {
+                    Some(node_before) => node_before.borrow_mut().next = next.clone(),
+                    None => self.list.head = next.clone(),
+                }
+                match &next {
+                    Some(node_after) => node_after.borrow_mut().prev = prev.clone(),
+                    None => self.list.tail = prev,
+                }
+
+                return Some(Rc::try_unwrap(node).ok().unwrap().into_inner().item);
+            }
+        }
+
+        None
+    }
+}
+
+impl<T: Ord> DLListDrop<T> {
+    pub fn drain<R: RangeBounds<T>>(&mut self, range: R) -> DropDrain<T> {
+        self.drain_by::<T, R>(range)
+    }
+
+    pub fn drain_by<Q: Ord + ?Sized, R: RangeBounds<Q>>(&mut self, range: R) -> DropDrain<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let mut drained = DLListDrop::new();
+
+        let mut first = self.head.clone();
+        while let Some(node) = first.clone() {
+            if !before_start(range.start_bound(), &node.borrow().item) {
+                break;
+            }
+            first = node.borrow().next.clone();
+        }
+
+        let first = match first {
+            Some(node) if !after_end(range.end_bound(), &node.borrow().item) => node,
+            _ => return DropDrain { list: drained },
+        };
+
+        let mut last = first.clone();
+        loop {
+            let next = last.borrow().next.clone();
+            match next {
+                Some(next) if !after_end(range.end_bound(), &next.borrow().item) => last = next,
+                _ => break,
+            }
+        }
+
+        let before = first.borrow_mut().prev.take();
+        let after = last.borrow_mut().next.take();
+
+        match &before {
+            Some(node_before) => node_before.borrow_mut().next = after.clone(),
+            None => self.head = after.clone(),
+        }
+        match &after {
+            Some(node_after) => node_after.borrow_mut().prev = before.clone(),
+            None => self.tail = before,
+        }
+
+        drained.head = Some(first);
+        drained.tail = Some(last);
+        DropDrain { list: drained }
+    }
+
+    pub fn extract_if<F: FnMut(&T) -> bool>(&mut self, pred: F) -> DropExtractIf<'_, T, F> {
+        let next = self.head.clone();
+        DropExtractIf {
+            list: self,
+            next,
+            pred,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled_list() -> DLList<i32> {
+        [8, 6, 17, 35, 888, 1, 0].into_iter().collect()
+    }
+
+    fn filled_drop_list() -> DLListDrop<i32> {
+        let mut dll = DLListDrop::new();
+        for ele in [8, 6, 17, 35, 888, 1, 0] {
+            dll.push(ele);
+        }
+        dll
+    }
+
+    #[test]
+    fn drain_ranges_weak() {
+        let mut dll = filled_list();
+        assert_eq!(dll.drain(..6).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(dll.drain(10..=35).collect::<Vec<_>>(), vec![17, 35]);
+        assert_eq!(dll.drain(900..).count(), 0);
+        assert_eq!(dll.drain(100..).rev().collect::<Vec<_>>(), vec![888]);
+
+        //head, tail und prev müssen noch stimmen
+        assert_eq!(dll.pop_back(), Some(8));
+        assert_eq!(dll.pop_front(), Some(6));
+        assert!(dll.is_empty());
+    }
+
+    #[test]
+    fn drain_borrowed_range_weak() {
+        let mut dll: DLList<String> = ["a", "ba", "bb", "c"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        //Bereich über &str statt String
+        let drained: Vec<String> = dll
+            .drain_by::<str, _>((Bound::Included("b"), Bound::Excluded("c")))
+            .collect();
+        assert_eq!(drained, vec!["ba", "bb"]);
+        assert_eq!(dll.to_vec(), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn drain_all_and_drop_weak() {
+        let mut dll = filled_list();
+        let first = dll.head.clone().unwrap();
+        let weak_first = Rc::downgrade(&first);
+        drop(first);
+
+        //Drain wird nicht durchlaufen, die Werte sind trotzdem entfernt und freigegeben
+        drop(dll.drain(..));
+        assert!(dll.is_empty());
+        assert!(dll.tail.is_none());
+        assert!(weak_first.upgrade().is_none());
+    }
+
+    #[test]
+    fn extract_if_weak() {
+        let mut dll = filled_list();
+
+        let mut odd = dll.extract_if(|x| x % 2 == 1);
+        assert_eq!(odd.next(), Some(1));
+        assert_eq!(odd.next(), Some(17));
+        assert_eq!(odd.next(), Some(35));
+        assert_eq!(odd.next(), None);
+
+        let nodes = crate::transaction::nodes(&dll);
+        assert_eq!(nodes.len(), 4);
+        drop(nodes);
+        assert_eq!(dll.to_vec(), vec![0, 6, 8, 888]);
+    }
+
+    #[test]
+    fn extract_if_ends_weak() {
+        let mut dll = filled_list();
+
+        let removed: Vec<_> = dll.extract_if(|x| *x == 0 || *x == 888).collect();
+        assert_eq!(removed, vec![0, 888]);
+        assert_eq!(dll.pop_front(), Some(1));
+        assert_eq!(dll.pop_back(), Some(35));
+    }
+
+    #[test]
+    fn drain_ranges_drop() {
+        let mut dll = filled_drop_list();
+        assert_eq!(dll.drain(..6).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(dll.drain(10..=35).collect::<Vec<_>>(), vec![17, 35]);
+        assert_eq!(dll.drain(900..).count(), 0);
+
+        assert_eq!(dll.pop_back(), Some(888));
+        assert_eq!(dll.pop_front(), Some(6));
+        assert_eq!(dll.to_vec(), vec![8]);
+    }
+
+    #[test]
+    fn drain_drop_frees_nodes() {
+        let mut dll = filled_drop_list();
+        let weak_head = Rc::downgrade(dll.head.as_ref().unwrap());
+
+        drop(dll.drain(..=8));
+        assert!(weak_head.upgrade().is_none());
+        assert_eq!(dll.to_vec(), vec![17, 35, 888]);
+    }
+
+    #[test]
+    fn extract_if_drop() {
+        let mut dll = filled_drop_list();
+
+        let removed: Vec<_> = dll.extract_if(|x| x % 2 == 0).collect();
+        assert_eq!(removed, vec![0, 6, 8, 888]);
+        assert_eq!(dll.pop_back(), Some(35));
+        assert_eq!(dll.to_vec(), vec![1, 17]);
+    }
+}
@@ -0,0 +1,340 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::borrow;
+
+use crate::DLList;
+
+/*Undo/Redo
+
+HistoryList ist eine DLList, bei der jeder ändernde Aufruf (push, pop_front, pop_back, remove)
+in einer History gespeichert wird. Mit undo() wird die letzte Änderung durch die inverse
+Operation rückgängig gemacht, mit redo() wieder ausgeführt.
+
+    push(x)        <-> remove(x)
+    pop_*()  -> x  <-> push(x)
+    remove(x)      <-> push(x)
+
+Da die Liste sortiert ist, reicht es den Wert zu speichern, die Position ergibt sich von selbst.
+Deshalb braucht T: Clone (der Wert wird zurückgegeben und in der History behalten).
+
+Die History kann begrenzt werden (with_capacity). Ist sie voll, wird die älteste Änderung vergessen.
+Mit checkpoint() kann eine Markierung gesetzt werden, undo_to_checkpoint() geht bis dorthin zurück.
+*/
+#[derive(Clone)]
+enum Operation<T> {
+    Insert(T),
+    Remove(T),
+}
+
+enum Entry<T> {
+    Op(Operation<T>),
+    Checkpoint,
+}
+
+pub struct HistoryList<T: Ord> {
+    list: DLList<T>,
+    undo_stack: VecDeque<Entry<T>>,
+    redo_stack: Vec<Entry<T>>,
+    //Anzahl der Operationen (ohne Checkpoints) im undo_stack
+    history_len: usize,
+    capacity: Option<usize>,
+}
+
+impl<T: Ord + Clone> HistoryList<T> {
+    //Liste mit unbegrenzter History
+    pub fn new() -> Self {
+        Self {
+            list: DLList::new(),
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            history_len: 0,
+            capacity: None,
+        }
+    }
+
+    //Liste, die sich höchstens capacity Änderungen merkt
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    pub fn push(&mut self, wert: T) {
+        self.list.push(wert.clone());
+        self.record(Operation::Insert(wert));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let wert = self.list.pop_front()?;
+        self.record(Operation::Remove(wert.clone()));
+        Some(wert)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let wert = self.list.pop_back()?;
+        self.record(Operation::Remove(wert.clone()));
+        Some(wert)
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let wert = self.list.remove(element)?;
+        self.record(Operation::Remove(wert.clone()));
+        Some(wert)
+    }
+
+    pub fn contains<Q: Ord + ?Sized>(&mut self, element: &Q) -> bool
+    where
+        T: borrow::Borrow<Q>,
+    {
+        self.list.contains(element)
+    }
+
+    //Gibt die Werte der Liste zurück, die History bleibt dabei erhalten
+    pub fn values(&self) -> Vec<T> {
+        let mut out = Vec::new();
+        let mut current = self.list.head.clone();
+
+        while let Some(curr) = current {
+            out.push(curr.borrow().item.clone());
+            current = curr.borrow().next.clone();
+        }
+
+        out
+    }
+
+    //Letzte Änderung rückgängig machen, false wenn es nichts rückgängig zu machen gibt
+    pub fn undo(&mut self) -> bool {
+        while let Some(entry) = self.undo_stack.pop_back() {
+            match entry {
+                Entry::Checkpoint => self.redo_stack.push(Entry::Checkpoint),
+                Entry::Op(op) => {
+                    self.history_len -= 1;
+                    self.apply(Self::inverse(&op));
+                    self.redo_stack.push(Entry::Op(op));
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    //Zuletzt rückgängig gemachte Änderung wiederholen
+    pub fn redo(&mut self) -> bool {
+        while let Some(entry) = self.redo_stack.pop() {
+            match entry {
+                Entry::Checkpoint => self.undo_stack.push_back(Entry::Checkpoint),
+                Entry::Op(op) => {
+                    self.apply(op.clone());
+                    self.push_entry(Entry::Op(op));
+                    return true;
+                }
+            }
+        }
+
+        false
+    }
+
+    //Markierung in der History setzen
+    pub fn checkpoint(&mut self) {
+        self.undo_stack.push_back(Entry::Checkpoint);
+    }
+
+    //Alle Änderungen bis zum letzten Checkpoint rückgängig machen, gibt die Anzahl zurück.
+    //Der Checkpoint wird dabei entfernt (landet im redo_stack), ein zweiter Aufruf geht
+    //also bis zum davor liegenden Checkpoint zurück.
+    pub fn undo_to_checkpoint(&mut self) -> usize {
+        let mut count = 0;
+
+        while let Some(entry) = self.undo_stack.pop_back() {
+            match entry {
+                Entry::Checkpoint => {
+                    self.redo_stack.push(Entry::Checkpoint);
+                    break;
+                }
+                Entry::Op(op) => {
+                    self.history_len -= 1;
+                    self.apply(Self::inverse(&op));
+                    self.redo_stack.push(Entry::Op(op));
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    //Anzahl der Änderungen, die rückgängig gemacht werden können
+    pub fn history_len(&self) -> usize {
+        self.history_len
+    }
+
+    //Anzahl der Änderungen, die wiederholt werden können
+    pub fn redo_len(&self) -> usize {
+        self.redo_stack
+            .iter()
+            .filter(|entry| matches!(entry, Entry::Op(_)))
+            .count()
+    }
+
+    pub fn clear_history(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+        self.history_len = 0;
+    }
+
+    //Neue Änderung merken, danach kann nichts mehr wiederholt werden
+    fn record(&mut self, op: Operation<T>) {
+        self.redo_stack.clear();
+        self.push_entry(Entry::Op(op));
+    }
+
+    fn push_entry(&mut self, entry: Entry<T>) {
+        self.undo_stack.push_back(entry);
+        self.history_len += 1;
+
+        //Bei voller History die ältesten Einträge (und davor liegende Checkpoints) vergessen
+        if let Some(capacity) = self.capacity {
+            while self.history_len > capacity {
+                if let Some(Entry::Op(_)) = self.undo_stack.pop_front() {
+                    self.history_len -= 1;
+                }
+            }
+            while let Some(Entry::Checkpoint) = self.undo_stack.front() {
+                self.undo_stack.pop_front();
+            }
+        }
+    }
+
+    fn inverse(op: &Operation<T>) -> Operation<T> {
+        match op {
+            Operation::Insert(wert) => Operation::Remove(wert.clone()),
+            Operation::Remove(wert) => Operation::Insert(wert.clone()),
+        }
+    }
+
+    fn apply(&mut self, op: Operation<T>) {
+        match op {
+            Operation::Insert(wert) => self.list.push(wert),
+            Operation::Remove(wert) => {
+                self.list.remove(&wert);
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone> Default for HistoryList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo_push_pop() {
+        let mut list = HistoryList::<i32>::new();
+
+        for ele in [8, 6, 17] {
+            list.push(ele);
+        }
+        assert_eq!(list.pop_front(), Some(6));
+        assert_eq!(list.pop_back(), Some(17));
+        assert_eq!(list.values(), vec![8]);
+        assert_eq!(list.history_len(), 5);
+
+        assert!(list.undo());
+        assert_eq!(list.values(), vec![8, 17]);
+        assert!(list.undo());
+        assert_eq!(list.values(), vec![6, 8, 17]);
+        assert!(list.undo());
+        assert_eq!(list.values(), vec![6, 8]);
+
+        assert!(list.redo());
+        assert_eq!(list.values(), vec![6, 8, 17]);
+        assert_eq!(list.history_len(), 3);
+        assert_eq!(list.redo_len(), 2);
+    }
+
+    #[test]
+    fn undo_remove() {
+        let mut list = HistoryList::<i32>::new();
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.remove(&2), Some(2));
+        assert_eq!(list.remove(&5), None);
+        assert_eq!(list.history_len(), 4);
+
+        list.undo();
+        assert_eq!(list.values(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn new_change_clears_redo() {
+        let mut list = HistoryList::<i32>::new();
+        list.push(1);
+        list.push(2);
+        list.undo();
+
+        list.push(3);
+        assert!(!list.redo());
+        assert_eq!(list.values(), vec![1, 3]);
+    }
+
+    #[test]
+    fn undo_on_empty_history() {
+        let mut list = HistoryList::<i32>::new();
+
+        assert!(!list.undo());
+        assert!(!list.redo());
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.history_len(), 0);
+    }
+
+    #[test]
+    fn checkpoints() {
+        let mut list = HistoryList::<i32>::new();
+        list.push(1);
+        list.checkpoint();
+        list.push(2);
+        list.push(3);
+        list.checkpoint();
+        list.pop_front();
+
+        assert_eq!(list.undo_to_checkpoint(), 1);
+        assert_eq!(list.values(), vec![1, 2, 3]);
+        assert_eq!(list.undo_to_checkpoint(), 2);
+        assert_eq!(list.values(), vec![1]);
+        assert_eq!(list.undo_to_checkpoint(), 1);
+        assert_eq!(list.values(), Vec::<i32>::new());
+
+        //Alles wiederholen, die Checkpoints kommen dabei wieder zurück
+        while list.redo() {}
+        assert_eq!(list.values(), vec![2, 3]);
+        assert_eq!(list.undo_to_checkpoint(), 1);
+        assert_eq!(list.values(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn bounded_history() {
+        let mut list = HistoryList::<i32>::with_capacity(3);
+
+        list.checkpoint();
+        for ele in 0..5 {
+            list.push(ele);
+        }
+        assert_eq!(list.history_len(), 3);
+
+        //Nur die letzten drei push können rückgängig gemacht werden
+        while list.undo() {}
+        assert_eq!(list.values(), vec![0, 1]);
+    }
+}
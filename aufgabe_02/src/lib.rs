@@ -0,0 +1,1011 @@
+/*Listen ohne std
+
+Die Listen brauchen nur Rc/Weak, Vec, Box und VecDeque aus alloc und RefCell/Cell aus core.
+Ohne das Feature "std" (cargo build --no-default-features) ist die Crate deshalb #![no_std] und
+kann in Umgebungen ohne Betriebssystem benutzt werden, solange es einen Allocator gibt.
+Nur die Extras, die std brauchen (Benchmarks mit Zeitmessung und Ausgabe), sind hinter "std".
+
+Die Tests laufen immer mit std. Prüfen, ob alles ohne std baut:
+    cargo build --no-default-features --all-targets
+Das Binary (Benchmarks) braucht std und wird dabei übersprungen (required-features in Cargo.toml).
+*/
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+//Nur das Modul, nicht den Trait importieren, sonst ist .borrow() auf den RefCells mehrdeutig
+use core::borrow;
+use core::cell::{Cell, RefCell};
+use core::cmp::Ordering;
+
+#[cfg(feature = "std")]
+pub mod bench;
+pub mod bounded;
+#[cfg(test)]
+mod differential;
+pub mod drain;
+pub mod history;
+pub mod map;
+pub mod modification;
+pub mod multi_index;
+pub mod observer;
+pub mod peek_mut;
+pub mod persistent;
+pub mod pool;
+pub mod raw;
+#[cfg(any(feature = "std", test))]
+mod rng;
+pub mod set_ops;
+pub mod transaction;
+pub mod unrolled;
+pub mod window;
+
+use modification::{ConcurrentModification, ModificationCheck};
+use observer::{ChangeKind, Observers};
+use pool::NodePool;
+
+/*Option
+    bedeutet, dass der Pointer auch leer sein kann, ähnlich wie ein nullptr in C, aber sicherer
+*/
+
+/*RC & Weak
+
+RC und Weak sind nicht mutable Zieger (also zeiger die nur Speicher lesen können aber nicht Schreiben)
+
+Link: einen "leeren" Ref. counter (Rc) mit veränderlichen Zugriff (RefCell) vom Typ Node
+    hat durch den Ref. Count eine Besitzanforderung auf den Speicher. Dadurch wird das Objekt vom Rc verfolgt.
+    Rc liegt auf dem Heap.
+    Rc zählt die zugriffe mit, soblad keine Zugriffe (Count = 0) mehr vorhanden sind, wird der Speicher freigegeben
+    Bei einem RC-Zeiger, wird der Speicher nur freigegeben, wenn der Ref. Count auf 0 steht, es also keine Referenzen auf den Speicher mehr gibt.
+
+WeakLink: wie link, "leere" Ref. counter (Rc) mit schwachen zugriff vom Type Node.
+    hat keine Besitzanforderung auf den Speicher, also zählt die zugriffe nicht mit.
+    liegt auch auf dem Heap.
+    Weak zählt keine zugriffe, daher wir eine Speicher nicht nur gehalten, wenn ein Weak -Zeiger drauf zeigt.
+    also wenn ein Weak-Zeiger auf einen Speicherplatz zeigt (egal was für eine art (i32, RC, Vec, etc)),
+    sobald der Besitzer des Speichers sein Scope verlässt, dann wird der Speicher freigegeben, auch wenn der Weak-Pointer noch drauf zeigt.
+*/
+
+/*RefCell
+RefCell<T> erlaubt es, Daten auch über einen Rc<RefCell<T>> zu verändern,
+obwohl Rc keine mutable Referenzen erlaubt.
+Weak-Zeiger müssen vorher mit .upgrade() in Rc umgewandelt werden, um Zugriff zu bekommen.
+*/
+type Link<T> = Rc<RefCell<Node<T>>>;
+type WeakLink<T> = Weak<RefCell<Node<T>>>;
+
+/*Datenstruktur für die Node:
+<T> bedeutet, dass die struct mit einem beliebigen Datentyp verwendet werden kann,
+der aber zur Compile-Zeit festgelegt wird.
+Beispiel:
+let liste1 = Node::<i32>::new();     // T = i32
+let liste2 = Node::<String>::new();  // T = String
+
+*/
+
+/*Aufbau
+Item: Speichert den DAten Inhalt
+    Da das Item nur bei erstellen die Daten "geändert" werden
+
+next: Zeiger auf nächste Node
+    Pointer der auf die nächste Node nach Rechts zeigt. Da sich das ändern kann Link
+
+prev: Zeiger auf vorherige Node
+    Pointer auf die vorherige Node nach links. Da Elemente getauscht werden können (siehe DLL_switch) WeakLink
+
+*/
+
+struct Node<T> {
+    item: T,
+    next: Option<Link<T>>,
+    prev: Option<WeakLink<T>>,
+}
+
+impl<T> Node<T> {
+    fn new(item: T) -> Self {
+        Self {
+            item,
+            next: None,
+            prev: None,
+        }
+    }
+}
+
+//Struktur für den Kopf- und Endstueck:
+
+fn to_weak<T>(node: &Option<Link<T>>) -> Option<WeakLink<T>> {
+    match node {
+        Some(link) => Some(Rc::downgrade(link)),
+        None => None,
+    }
+}
+
+fn get_next<T>(link: &Link<T>) -> Option<Link<T>> {
+    link.as_ref().borrow_mut().next.clone()
+}
+
+fn set_next<T>(link: &Link<T>, next: Option<Link<T>>) {
+    link.as_ref().borrow_mut().next = next;
+}
+
+fn get_prev<T>(link: &Link<T>) -> Option<WeakLink<T>> {
+    link.as_ref().borrow_mut().prev.clone()
+}
+
+fn set_prev<T>(link: &Link<T>, prev: Option<WeakLink<T>>) {
+    link.as_ref().borrow_mut().prev = prev;
+}
+
+pub struct DLList<T> {
+    head: Option<Link<T>>,
+    tail: Option<Link<T>>,
+    //Freiliste für ausgehängte Knoten (siehe pool.rs), standardmäßig aus
+    pool: NodePool<T>,
+    //Zähler, der bei jeder Änderung der Verkettung erhöht wird. Iteratoren teilen sich den
+    //Zähler mit der Liste und erkennen so, wenn die Liste während der Iteration verändert wird.
+    modifications: Rc<Cell<usize>>,
+    //Angemeldete Beobachter, die bei jedem Einfügen/Entfernen informiert werden (siehe observer.rs)
+    observers: Observers<T>,
+}
+
+impl<T: Ord> DLList<T> {
+    //Erstellen eine DLL mit Head und Tail
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            pool: NodePool::disabled(),
+            modifications: Rc::new(Cell::new(0)),
+            observers: Observers::none(),
+        }
+    }
+
+    pub fn push(&mut self, wert: T) {
+        let mut node = self.head.clone();
+
+        while let Some(ref n) = node {
+            if n.as_ref().borrow().item >= wert {
+                break;
+            }
+            node = get_next(n);
+        }
+
+        let new_node = self.pool.alloc(wert);
+        let new_node_opt = Some(new_node.clone());
+        self.modified();
+
+        //Node is None -> am ende einfügen
+
+        match node.clone() {
+            None => {
+                match self.tail.clone() {
+                    // Liste ist Leer
+                    None => {
+                        self.head = new_node_opt.clone();
+                        self.tail = new_node_opt;
+                    }
+                    Some(tail_node) => {
+                        // am Ende einfügen
+                        set_next(&tail_node, new_node_opt.clone());
+                        set_prev(&new_node, to_weak(&self.tail));
+                        self.tail = new_node_opt;
+                    }
+                };
+            }
+            Some(node_after) => {
+                match get_prev(&node_after).clone() {
+                    //Insert at beginning
+                    None => {
+                        set_prev(&node_after, to_weak(&new_node_opt.clone()));
+                        set_next(&new_node, Some(node_after.clone()));
+                        self.head = new_node_opt.clone();
+                    }
+
+                    //Insert between two nodes
+                    Some(weak_node_before) => {
+                        let node_before = weak_node_before.upgrade().unwrap();
+                        set_prev(&node_after, to_weak(&new_node_opt.clone()));
+                        set_next(&node_before, new_node_opt.clone());
+
+                        set_prev(&new_node.clone(), Some(weak_node_before.clone()));
+                        set_next(&new_node.clone(), node.clone());
+                    }
+                };
+            }
+        }
+
+        let prev = get_prev(&new_node).and_then(|weak| weak.upgrade());
+        self.observers
+            .notify(ChangeKind::Inserted, &new_node, prev.as_ref(), node.as_ref());
+    }
+
+    //Funktion zum entfernen des ersten Elements (Linkes Element):
+    #[allow(clippy::question_mark)]
+    pub fn pop_front(&mut self) -> Option<T> {
+        //Wenn die Liste leer ist, wird "none" zurückgegeben:
+        if self.head.is_none() {
+            return None;
+        }
+
+        //aktuellen Head übetragen und nächsten Knoten holen
+        let old_head = self.head.take().unwrap();
+        let next = get_next(&old_head);
+        set_next(&old_head, None);
+        self.modified();
+        self.observers
+            .notify(ChangeKind::Removed, &old_head, None, next.as_ref());
+
+        //Wenn du nächste Konten leer ist, dann ist die Liste komplett leer und
+        //head und Tail werden auf none gesetzt, wenn nicht wird der prev von der
+        //nächsten Node gesetzt und der head auf den neuen Head gesetzt.
+        match next {
+            None => {
+                self.head = None;
+                self.tail = None;
+            }
+            Some(next_node) => {
+                set_prev(&next_node, None);
+                self.head = Some(next_node);
+            }
+        };
+
+        //den Wert des alten Head ausgeben (der Knoten geht dabei in den Pool, falls aktiv):
+        Some(self.pool.free(old_head))
+    }
+
+    //Funktion zum entfernen des letzten Elements (Rechtes Element):
+    #[allow(clippy::question_mark)]
+    pub fn pop_back(&mut self) -> Option<T> {
+        // Wenn die Liste leer ist, wird "None" zurückgegeben
+        if self.tail.is_none() {
+            return None;
+        }
+
+        // aktuellen Tail referenzieren und vorherigen Knoten holen
+        let old_tail = self.tail.take().unwrap();
+        let prev = get_prev(&old_tail);
+        set_prev(&old_tail, None);
+        self.modified();
+        let prev_strong = prev.as_ref().and_then(|weak| weak.upgrade());
+        self.observers
+            .notify(ChangeKind::Removed, &old_tail, prev_strong.as_ref(), None);
+
+        // Wenn der vorherige Knoten leer ist, war das Element das einzige in der Liste
+        // -> head und tail werden auf None gesetzt
+        // Wenn nicht, dann wird der next-Zeiger des vorigen Knotens auf None gesetzt,
+        // und der tail entsprechend aktualisiert
+
+        match prev {
+            None => {
+                self.head = None;
+                self.tail = None;
+            }
+            Some(prev_node) => {
+                if let Some(prev_strong) = prev_node.upgrade() {
+                    set_next(&prev_strong, None);
+                    self.tail = Some(prev_strong);
+                }
+            }
+        };
+
+        // den Wert des alten Tails ausgeben
+        Some(self.pool.free(old_tail))
+        /*
+                match Rc::try_unwrap(old_tail) {
+                    Ok(node_cell) => {
+                        let nodeS = node_cell.into_inner();
+                        Some(nodeS.item)
+                    }
+                    Err(rc) => {
+                        // Wenn noch andere Referenzen existieren, extrahieren wir trotzdem den Wert
+                        let node_ref = rc.borrow();
+                        //Some(node_ref.item.clone()) // T: Clone nötig
+                        Some(node_ref.item)
+                    }
+                }
+        */
+    }
+
+    pub fn to_vec(&mut self) -> Vec<T> {
+        let mut out_vec: Vec<T> = Vec::new();
+
+        while let Some(val) = self.pop_front() {
+            out_vec.push(val);
+        }
+
+        out_vec
+    }
+
+    //Wie bei den std Collections kann mit einer geliehenen Form gesucht werden,
+    //z.B. DLList<String> mit &str
+    pub fn contains<Q: Ord + ?Sized>(&mut self, element: &Q) -> bool
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let mut current = self.head.clone();
+
+        while let Some(ref curr) = current {
+            if compare(&curr.borrow().item, element) == Ordering::Equal {
+                return true;
+            }
+
+            current = get_next(curr);
+        }
+
+        false
+    }
+
+    //Funktion zum entfernen eines beliebigen Elements, gibt den Wert zurück wenn er gefunden wurde:
+    pub fn remove<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let node = self.find_node(element)?;
+        self.unlink_node(&node);
+        Some(self.pool.free(node))
+    }
+
+    //Sucht den ersten Knoten mit dem Wert. Da die Liste sortiert ist, kann abgebrochen werden,
+    //sobald ein größerer Wert kommt.
+    fn find_node<Q: Ord + ?Sized>(&self, element: &Q) -> Option<Link<T>>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let mut current = self.head.clone();
+
+        while let Some(curr) = current {
+            let ordering = compare(&curr.borrow().item, element);
+            match ordering {
+                Ordering::Equal => return Some(curr),
+                Ordering::Greater => return None,
+                Ordering::Less => {}
+            }
+            current = get_next(&curr);
+        }
+
+        None
+    }
+
+    //Wie push, gibt aber den neuen Knoten zurück (z.B. um ihn später gezielt auszuhängen)
+    fn push_node(&mut self, wert: T) -> Link<T> {
+        let (prev, next) = self.insert_position(&wert);
+        let new_node = self.pool.alloc(wert);
+
+        self.link_node(&new_node, prev, next);
+        new_node
+    }
+
+    //Gibt die Nachbarn (prev, next) zurück, zwischen die ein neuer Wert eingefügt werden muss.
+    //Gleiche Werte werden wie bei push vor den vorhandenen eingefügt.
+    fn insert_position(&self, wert: &T) -> (Option<Link<T>>, Option<Link<T>>) {
+        let mut node = self.head.clone();
+
+        while let Some(ref n) = node {
+            if n.borrow().item >= *wert {
+                break;
+            }
+            node = get_next(n);
+        }
+
+        match node {
+            None => (self.tail.clone(), None),
+            Some(node_after) => {
+                let node_before = get_prev(&node_after).and_then(|weak| weak.upgrade());
+                (node_before, Some(node_after))
+            }
+        }
+    }
+}
+
+/*Hilfsfunktionen zum Ein- und Aushängen von Knoten
+
+Werden von remove und den Erweiterungen (Transaktionen, ...) benutzt. Der Knoten selbst bleibt
+dabei erhalten, dadurch kann ein ausgehängter Knoten später genau an der gleichen Stelle
+wieder eingehängt werden.
+*/
+impl<T> DLList<T> {
+    //Knoten zwischen prev und next einhängen, head und tail werden angepasst
+    fn link_node(&mut self, node: &Link<T>, prev: Option<Link<T>>, next: Option<Link<T>>) {
+        self.modified();
+        set_prev(node, to_weak(&prev));
+        set_next(node, next.clone());
+
+        match &prev {
+            Some(node_before) => set_next(node_before, Some(node.clone())),
+            None => self.head = Some(node.clone()),
+        }
+        match &next {
+            Some(node_after) => set_prev(node_after, Some(Rc::downgrade(node))),
+            None => self.tail = Some(node.clone()),
+        }
+
+        self.observers
+            .notify(ChangeKind::Inserted, node, prev.as_ref(), next.as_ref());
+    }
+
+    //Knoten aus der Liste aushängen, gibt die alten Nachbarn (prev, next) zurück
+    fn unlink_node(&mut self, node: &Link<T>) -> (Option<Link<T>>, Option<Link<T>>) {
+        self.modified();
+        let prev = node.borrow_mut().prev.take().and_then(|weak| weak.upgrade());
+        let next = node.borrow_mut().next.take();
+
+        match &prev {
+            Some(node_before) => set_next(node_before, next.clone()),
+            None => self.head = next.clone(),
+        }
+        match &next {
+            Some(node_after) => set_prev(node_after, to_weak(&prev)),
+            None => self.tail = prev.clone(),
+        }
+
+        self.observers
+            .notify(ChangeKind::Removed, node, prev.as_ref(), next.as_ref());
+        (prev, next)
+    }
+
+    //Änderungszähler erhöhen (siehe Iter)
+    fn modified(&self) {
+        self.modifications.set(self.modifications.get().wrapping_add(1));
+    }
+
+    //Anzahl der bisherigen Änderungen an der Verkettung
+    pub fn modification_count(&self) -> usize {
+        self.modifications.get()
+    }
+}
+
+//Wert mit einem Suchwert vergleichen, der eine geliehene Form von T ist (T: Borrow<Q>)
+fn compare<T: borrow::Borrow<Q>, Q: Ord + ?Sized>(item: &T, element: &Q) -> Ordering {
+    borrow::Borrow::<Q>::borrow(item).cmp(element)
+}
+
+//Wert aus einem ausgehängten Knoten holen, es darf keine andere Referenz mehr auf den Knoten geben
+fn take_item<T>(node: Link<T>) -> T {
+    Rc::try_unwrap(node).ok().unwrap().into_inner().item
+}
+
+/*Iteratoren
+
+Iter hält selbst einen Zeiger auf den nächsten Knoten (kein Borrow der Liste). Da die Werte in einer
+RefCell liegen, kann keine &T Referenz herausgegeben werden, es wird eine Kopie zurückgegeben.
+
+Weil die Liste nicht ausgeliehen ist, kann sie während der Iteration verändert werden. Damit dabei
+keine Werte still übersprungen oder doppelt ausgegeben werden, merkt sich Iter den Änderungszähler
+der Liste beim Erstellen (siehe modification.rs). Hat er sich geändert, gibt try_next Err(ConcurrentModification) zurück
+und next löst eine Panik aus. Der nächste Knoten wird nur als Weak gehalten, damit pop/remove
+den Knoten trotzdem freigeben können.
+
+IntoIter verbraucht die Liste und gibt die Werte mit pop_front der Reihe nach aus.
+*/
+pub struct Iter<T> {
+    next: Option<WeakLink<T>>,
+    check: ModificationCheck,
+}
+
+impl<T: Clone> Iter<T> {
+    pub fn try_next(&mut self) -> Result<Option<T>, ConcurrentModification> {
+        self.check.check()?;
+
+        let node = match self.next.take() {
+            None => return Ok(None),
+            Some(weak) => weak.upgrade().ok_or(ConcurrentModification)?,
+        };
+        self.next = to_weak(&get_next(&node));
+        let item = node.borrow().item.clone();
+        Ok(Some(item))
+    }
+}
+
+impl<T: Clone> Iterator for Iter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.try_next() {
+            Ok(item) => item,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+pub struct IntoIter<T> {
+    list: DLList<T>,
+}
+
+impl<T: Ord> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T: Ord> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T: Ord> IntoIterator for DLList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<T: Ord> Default for DLList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for DLList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DLList::new();
+        for wert in iter {
+            list.push(wert);
+        }
+        list
+    }
+}
+
+impl<T> DLList<T> {
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            next: to_weak(&self.head),
+            check: self.modification_check(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    //Ersten Knoten aushängen ohne den Wert herauszuholen
+    fn pop_front_node(&mut self) -> Option<Link<T>> {
+        let node = self.head.clone()?;
+        self.unlink_node(&node);
+        Some(node)
+    }
+
+    //Knoten am Ende anhängen. Darf nur benutzt werden, wenn der Wert >= dem letzten Wert ist,
+    //sonst ist die Liste nicht mehr sortiert.
+    fn push_back_node(&mut self, node: Link<T>) {
+        let tail = self.tail.clone();
+        self.link_node(&node, tail, None);
+    }
+}
+
+type DropLink<T> = Rc<RefCell<DropNode<T>>>;
+
+struct DropNode<T> {
+    item: T,
+    next: Option<DropLink<T>>,
+    prev: Option<DropLink<T>>,
+}
+
+impl<T> DropNode<T> {
+    fn new(item: T) -> Self {
+        Self {
+            item,
+            next: None,
+            prev: None,
+        }
+    }
+}
+
+pub struct DLListDrop<T> {
+    head: Option<DropLink<T>>,
+    tail: Option<DropLink<T>>,
+}
+
+impl<T: Ord> Default for DLListDrop<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DLListDrop<T> {
+    fn drop(&mut self) {
+        while let Some(node) = self.head.take() {
+            let _ = node.borrow_mut().prev.take();
+            self.head = node.borrow_mut().next.take();
+        }
+        self.tail.take();
+    }
+}
+
+impl<T: Ord> DLListDrop<T> {
+    //Erstellen eine DLL mit Head und Tail
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+        }
+    }
+    pub fn push(&mut self, wert: T) {
+        let mut node = self.head.clone();
+
+        while let Some(ref n) = node.clone() {
+            if n.as_ref().borrow().item >= wert {
+                break;
+            }
+            node = n.borrow().next.clone();
+        }
+
+        let new_node = Rc::new(RefCell::new(DropNode::new(wert)));
+        let new_node_opt = Some(new_node.clone());
+
+        //Node is None -> am ende einfügen
+
+        match node.clone() {
+            None => {
+                match self.tail.clone() {
+                    // Liste ist Leer
+                    None => {
+                        self.head = new_node_opt.clone();
+                        self.tail = new_node_opt;
+                    }
+                    Some(tail_node) => {
+                        // am Ende einfügen
+                        tail_node.borrow_mut().next = new_node_opt.clone();
+                        new_node.borrow_mut().prev = self.tail.clone();
+                        self.tail = new_node_opt;
+                    }
+                };
+            }
+            Some(node_after) => {
+
+                /*
+                Hier gab es ein Panik, weil der  die node_after danach mehrfach referenziert wird, aber der mut noch im scope war.
+                durch das let ... wird der Scope beendert und der borrwo_mut kann auf die node_after zugreifen.
+                 */
+                let maybe_node_after = node_after.borrow().prev.clone();
+
+                match  maybe_node_after{
+                    //Insert at beginning
+                    None => {
+                        node_after.borrow_mut().prev = new_node_opt.clone();
+                        new_node.borrow_mut().next = Some(node_after.clone());
+                        self.head = new_node_opt.clone();
+                    }
+
+                    //Insert between two nodes
+                    Some(node_before) => {
+                        node_after.borrow_mut().prev = new_node_opt.clone();
+                        node_before.borrow_mut().next = new_node_opt.clone();
+
+                        new_node.borrow_mut().prev = Some(node_before);
+                        new_node.borrow_mut().next = node;
+                    }
+                };
+            }
+        }
+    }
+    #[allow(clippy::question_mark)]
+    pub fn pop_front(&mut self) -> Option<T> {
+        //Wenn die Liste leer ist, wird "none" zurückgegeben:
+        if self.head.is_none() {
+            return None;
+        }
+
+        //aktuellen Head übetragen und nächsten Knoten holen
+        let old_head = self.head.take().unwrap();
+        let next = old_head.borrow().next.clone();
+
+        //Wenn du nächste Konten leer ist, dann ist die Liste komplett leer und
+        //head und Tail werden auf none gesetzt, wenn nicht wird der prev von der
+        //nächsten Node gesetzt und der head auf den neuen Head gesetzt.
+        match next {
+            None => {
+                self.head = None;
+                self.tail = None;
+            }
+            Some(next_node) => {
+                next_node.borrow_mut().prev = None;
+                self.head = Some(next_node);
+            }
+        };
+
+        //den Wert des alten Head ausgeben:
+        Some(Rc::try_unwrap(old_head).ok().unwrap().into_inner().item)
+    }
+    #[allow(clippy::question_mark)]
+    pub fn pop_back(&mut self) -> Option<T> {
+        // Wenn die Liste leer ist, wird "None" zurückgegeben
+        if self.tail.is_none() {
+            return None;
+        }
+
+        // aktuellen Tail referenzieren und vorherigen Knoten holen
+        let old_tail = self.tail.take().unwrap();
+        let prev = old_tail.borrow().prev.clone();
+
+        // Wenn der vorherige Knoten leer ist, war das Element das einzige in der Liste
+        // -> head und tail werden auf None gesetzt
+        // Wenn nicht, dann wird der next-Zeiger des vorigen Knotens auf None gesetzt,
+        // und der tail entsprechend aktualisiert
+
+        match prev {
+            None => {
+                self.head = None;
+                self.tail = None;
+            }
+            Some(prev_node) => {
+                prev_node.borrow_mut().next = None;
+                self.tail = Some(prev_node);
+            }
+        };
+
+        // den Wert des alten Tails ausgeben
+        Some(Rc::try_unwrap(old_tail).ok().unwrap().into_inner().item)
+    }
+    pub fn to_vec(&mut self) -> Vec<T> {
+        let mut out_vec: Vec<T> = Vec::new();
+
+        while let Some(val) = self.pop_front() {
+            out_vec.push(val);
+        }
+
+        out_vec
+    }
+    
+
+    pub fn contains<Q: Ord + ?Sized>(&mut self, element: &Q) -> bool
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let mut current = self.head.clone();
+
+        while let Some(ref curr) = current.clone() {
+            if compare(&curr.borrow_mut().item, element) == Ordering::Equal {
+                return true;
+            }
+
+            current = curr.borrow().next.clone();
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+//Weak tests:
+
+    #[test]
+    fn sort_test_weak() {
+        let mut dll = DLList::<i32>::new();
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        let exp_vec = vec![0, 1, 6, 8, 17, 35, 888];
+
+        assert_eq!(dll.to_vec(), exp_vec);
+    }
+
+    #[test]
+    fn empty_list_function_test_weak() {
+        let mut dll = DLList::<i32>::new();
+
+        assert_eq!(dll.to_vec(), vec![]);
+        assert_eq!(dll.pop_back(), None);
+        assert_eq!(dll.pop_front(), None);
+    }
+
+    #[test]
+    fn pop_front_pop_back_weak() {
+        let mut dll = DLList::<i32>::new();
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        assert_eq!(dll.pop_front(), Some(0));
+        assert_eq!(dll.pop_back(), Some(888));
+        assert_eq!(dll.pop_back(), Some(35));
+        assert_eq!(dll.pop_front(), Some(1));
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn contains_test_weak() {
+        let mut dll = DLList::<i32>::new();
+
+        //Test bei Leerer Liste
+        assert_eq!(dll.contains(&18), false);
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        //Test bei voller Liste
+        assert_eq!(dll.contains(&17), true);
+        assert_eq!(dll.contains(&18), false);
+    }
+
+    #[test]
+    fn remove_test_weak() {
+        let mut dll = DLList::<i32>::new();
+
+        assert_eq!(dll.remove(&3), None);
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        //Anfang, Mitte und Ende entfernen
+        assert_eq!(dll.remove(&0), Some(0));
+        assert_eq!(dll.remove(&17), Some(17));
+        assert_eq!(dll.remove(&888), Some(888));
+        assert_eq!(dll.remove(&18), None);
+
+        assert_eq!(dll.pop_back(), Some(35));
+        assert_eq!(dll.to_vec(), vec![1, 6, 8]);
+    }
+
+    #[test]
+    fn borrowed_lookup_weak() {
+        //Suchen mit &str in einer Liste von Strings, ohne einen String anzulegen
+        let mut dll: DLList<String> = ["birne", "apfel", "kiwi"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert!(dll.contains("apfel"));
+        assert!(!dll.contains("banane"));
+        assert_eq!(dll.remove("birne"), Some(String::from("birne")));
+        assert_eq!(dll.remove("birne"), None);
+        assert_eq!(dll.to_vec(), vec!["apfel", "kiwi"]);
+    }
+
+    #[test]
+    fn stress_test_weak() {
+        let mut dll = DLList::<i32>::new();
+
+        //Liste mit Werten füllen
+        for ele in 0..1000 {
+            dll.push(ele);
+        }
+
+        let expected: Vec<_> = (0..1000).collect();
+        assert_eq!(dll.to_vec(), expected);
+    }
+
+    #[test]
+    fn memory_leak_weak() {
+        let mut list = DLList::<i32>::new();
+        list.push(10);
+        list.push(20);
+        list.push(30);
+
+        // Zugriff auf Knoten für Überwachung
+        let second_node = list.head.as_ref().unwrap().borrow().next.as_ref().unwrap().clone();
+        let weak_second = Rc::downgrade(&second_node);
+
+        assert_eq!(Rc::strong_count(&second_node), 2);
+        assert_eq!(Rc::weak_count(&second_node), 2); // durch prev
+
+        // Entferne manuell alle Verbindungen (simuliert clear/drop)
+        drop(second_node);
+        drop(list); // falls du eine eigene clear() hast
+
+        // Sollte jetzt kein Upgrade mehr möglich sein
+        assert!(weak_second.upgrade().is_none());
+    }
+
+//Drop Test:
+    #[test]
+    fn sort_test_drop() {
+        let mut dll = DLListDrop::<i32>::new();
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        let exp_vec = vec![0, 1, 6, 8, 17, 35, 888];
+
+        assert_eq!(dll.to_vec(), exp_vec);
+    }
+
+    #[test]
+    fn empty_list_function_test_drop() {
+        let mut dll = DLListDrop::<i32>::new();
+
+        assert_eq!(dll.to_vec(), vec![]);
+        assert_eq!(dll.pop_back(), None);
+        assert_eq!(dll.pop_front(), None);
+    }
+
+    #[test]
+    fn pop_front_pop_back_drop() {
+        let mut dll = DLListDrop::<i32>::new();
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        assert_eq!(dll.pop_front(), Some(0));
+        assert_eq!(dll.pop_back(), Some(888));
+        assert_eq!(dll.pop_back(), Some(35));
+        assert_eq!(dll.pop_front(), Some(1));
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn contains_test_drop() {
+        let mut dll = DLListDrop::<i32>::new();
+
+        //Test bei Leerer Liste
+        assert_eq!(dll.contains(&18), false);
+
+        let value_vec = vec![8, 6, 17, 35, 888, 1, 0];
+
+        for ele in value_vec {
+            dll.push(ele);
+        }
+
+        //Test bei voller Liste
+        assert_eq!(dll.contains(&17), true);
+        assert_eq!(dll.contains(&18), false);
+
+        let mut words = DLListDrop::<String>::new();
+        words.push(String::from("kiwi"));
+        assert!(words.contains("kiwi"));
+    }
+
+    #[test]
+    fn stress_test_drop() {
+        let mut dll = DLListDrop::<i32>::new();
+
+        //Liste mit Werten füllen
+        for ele in 0..1000 {
+            dll.push(ele);
+        }
+
+        let expected: Vec<_> = (0..1000).collect();
+        assert_eq!(dll.to_vec(), expected);
+    }
+
+    #[test]
+    fn memory_leak_drop() {
+        let mut list = DLList::<i32>::new();
+        list.push(10);
+        list.push(20);
+        list.push(30);
+
+        // Zugriff auf Knoten für Überwachung
+        let second_node = list.head.as_ref().unwrap().borrow().next.as_ref().unwrap().clone();
+        //let weak_second = Rc::downgrade(&second_node);
+
+        assert_eq!(Rc::strong_count(&second_node), 2);
+        //assert_eq!(Rc::weak_count(&second_node), 2); // durch prev
+
+        // Entferne manuell alle Verbindungen (simuliert clear/drop)
+        drop(second_node);
+        drop(list); // falls du eine eigene clear() hast
+
+        // Sollte jetzt kein Upgrade mehr möglich sein
+        //assert!(weak_second.upgrade().is_none());
+    }
+
+}
@@ -0,0 +1,525 @@
+use alloc::vec::Vec;
+use core::borrow;
+use core::cell::{Ref, RefMut};
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
+use crate::modification::{ConcurrentModification, ModificationCheck};
+use crate::{DLList, Link, WeakLink, compare, get_next, to_weak};
+
+/*Sortierte Map DLMap<K, V>
+
+DLList<T> speichert nur Werte, die selbst Ord sind. Für Schlüssel mit Nutzdaten müsste man eine
+Wrapper-Struct mit eigenem Ord schreiben. DLMap macht genau das intern: jeder Knoten der DLList
+enthält einen MapEntry { key, value }, verglichen wird nur über den Schlüssel.
+Jeder Schlüssel kommt höchstens einmal vor.
+
+Zugriff auf die Werte:
+    Die Werte liegen in einer RefCell, deshalb kann keine &V Referenz zurückgegeben werden.
+    get/get_mut/entry geben stattdessen einen Handle zurück, der den Knoten (Rc) festhält.
+    Über den Handle bekommt man Ref<V> bzw. RefMut<V>:
+
+        *map.get_mut(&"a").unwrap().get_mut() += 1;
+        *map.entry("b").or_insert(0).get_mut() += 1;
+*/
+struct MapEntry<K, V> {
+    key: K,
+    value: V,
+}
+
+//Vergleich nur über den Schlüssel, damit die DLList nach Schlüsseln sortiert
+impl<K: Ord, V> PartialEq for MapEntry<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> Eq for MapEntry<K, V> {}
+
+impl<K: Ord, V> PartialOrd for MapEntry<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V> Ord for MapEntry<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+type MapLink<K, V> = Link<MapEntry<K, V>>;
+
+pub struct DLMap<K, V> {
+    list: DLList<MapEntry<K, V>>,
+    len: usize,
+}
+
+//Lesender Zugriff auf einen Eintrag, hält den Knoten fest.
+//Die Map bleibt solange ausgeliehen, sonst könnte der Eintrag währenddessen entfernt werden.
+pub struct ValueRef<'a, K, V> {
+    node: MapLink<K, V>,
+    marker: PhantomData<&'a DLMap<K, V>>,
+}
+
+impl<K, V> ValueRef<'_, K, V> {
+    pub fn key(&self) -> Ref<'_, K> {
+        Ref::map(self.node.borrow(), |node| &node.item.key)
+    }
+
+    pub fn get(&self) -> Ref<'_, V> {
+        Ref::map(self.node.borrow(), |node| &node.item.value)
+    }
+}
+
+//Schreibender Zugriff, leiht die Map solange mutable aus, damit der Eintrag nicht entfernt wird
+pub struct ValueMut<'a, K, V> {
+    node: MapLink<K, V>,
+    marker: PhantomData<&'a mut DLMap<K, V>>,
+}
+
+impl<K, V> ValueMut<'_, K, V> {
+    pub fn key(&self) -> Ref<'_, K> {
+        Ref::map(self.node.borrow(), |node| &node.item.key)
+    }
+
+    pub fn get(&self) -> Ref<'_, V> {
+        Ref::map(self.node.borrow(), |node| &node.item.value)
+    }
+
+    pub fn get_mut(&mut self) -> RefMut<'_, V> {
+        RefMut::map(self.node.borrow_mut(), |node| &mut node.item.value)
+    }
+
+    //Wert ersetzen, gibt den alten Wert zurück
+    pub fn set(&mut self, value: V) -> V {
+        core::mem::replace(&mut *self.get_mut(), value)
+    }
+}
+
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    map: &'a mut DLMap<K, V>,
+    node: MapLink<K, V>,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    map: &'a mut DLMap<K, V>,
+    key: K,
+}
+
+impl<K: Ord, V> DLMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            list: DLList::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //Knoten zum Schlüssel suchen, abbrechen sobald ein größerer Schlüssel kommt
+    fn find_node<Q: Ord + ?Sized>(&self, key: &Q) -> Option<MapLink<K, V>>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        let mut current = self.list.head.clone();
+
+        while let Some(curr) = current {
+            let ordering = compare(&curr.borrow().item.key, key);
+            match ordering {
+                Ordering::Equal => return Some(curr.clone()),
+                Ordering::Greater => return None,
+                Ordering::Less => {}
+            }
+            current = get_next(&curr);
+        }
+
+        None
+    }
+
+    //Fügt ein Paar ein. Gibt es den Schlüssel schon, wird der Wert ersetzt und der alte zurückgegeben.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.find_node(&key) {
+            Some(node) => Some(core::mem::replace(&mut node.borrow_mut().item.value, value)),
+            None => {
+                self.list.push_node(MapEntry { key, value });
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    //Suche wie bei BTreeMap auch über eine geliehene Form des Schlüssels (z.B. &str bei String)
+    pub fn contains_key<Q: Ord + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: borrow::Borrow<Q>,
+    {
+        self.find_node(key).is_some()
+    }
+
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<ValueRef<'_, K, V>>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        self.find_node(key).map(|node| ValueRef {
+            node,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn get_mut<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<ValueMut<'_, K, V>>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        self.find_node(key).map(|node| ValueMut {
+            node,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    pub fn remove_entry<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        let node = self.find_node(key)?;
+        Some(self.remove_node(node))
+    }
+
+    fn remove_node(&mut self, node: MapLink<K, V>) -> (K, V) {
+        self.list.unlink_node(&node);
+        self.len -= 1;
+        let entry = self.list.pool.free(node);
+        (entry.key, entry.value)
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.find_node(&key) {
+            Some(node) => Entry::Occupied(OccupiedEntry { map: self, node }),
+            None => Entry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    //Kleinstes und größtes Paar entfernen
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let node = self.list.head.clone()?;
+        Some(self.remove_node(node))
+    }
+
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let node = self.list.tail.clone()?;
+        Some(self.remove_node(node))
+    }
+}
+
+impl<K: Clone, V: Clone> DLMap<K, V> {
+    //Paare aufsteigend nach Schlüssel (als Kopie, wie Iter bei DLList)
+    pub fn iter(&self) -> MapIter<K, V> {
+        MapIter {
+            next: to_weak(&self.list.head),
+            check: self.list.modification_check(),
+        }
+    }
+}
+
+impl<K: Clone, V> DLMap<K, V> {
+    pub fn keys(&self) -> Vec<K> {
+        let mut out = Vec::with_capacity(self.len);
+        let mut current = self.list.head.clone();
+
+        while let Some(curr) = current {
+            out.push(curr.borrow().item.key.clone());
+            current = get_next(&curr);
+        }
+
+        out
+    }
+}
+
+impl<K: Ord, V> Default for DLMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for DLMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = DLMap::new();
+        for (key, value) in iter {
+            map.insert(key, value);
+        }
+        map
+    }
+}
+
+//Prüft wie Iter bei DLList, ob die Map während der Iteration verändert wurde
+pub struct MapIter<K, V> {
+    next: Option<WeakLink<MapEntry<K, V>>>,
+    check: ModificationCheck,
+}
+
+impl<K: Clone, V: Clone> MapIter<K, V> {
+    pub fn try_next(&mut self) -> Result<Option<(K, V)>, ConcurrentModification> {
+        self.check.check()?;
+
+        let node = match self.next.take() {
+            None => return Ok(None),
+            Some(weak) => weak.upgrade().ok_or(ConcurrentModification)?,
+        };
+        self.next = to_weak(&get_next(&node));
+        let entry = &node.borrow().item;
+        Ok(Some((entry.key.clone(), entry.value.clone())))
+    }
+}
+
+impl<K: Clone, V: Clone> Iterator for MapIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        match self.try_next() {
+            Ok(entry) => entry,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+pub struct MapIntoIter<K, V> {
+    map: DLMap<K, V>,
+}
+
+impl<K: Ord, V> Iterator for MapIntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<(K, V)> {
+        self.map.pop_first()
+    }
+}
+
+impl<K: Ord, V> DoubleEndedIterator for MapIntoIter<K, V> {
+    fn next_back(&mut self) -> Option<(K, V)> {
+        self.map.pop_last()
+    }
+}
+
+impl<K: Ord, V> IntoIterator for DLMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = MapIntoIter<K, V>;
+
+    fn into_iter(self) -> MapIntoIter<K, V> {
+        MapIntoIter { map: self }
+    }
+}
+
+impl<'a, K: Ord, V> Entry<'a, K, V> {
+    pub fn or_insert(self, default: V) -> ValueMut<'a, K, V> {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> ValueMut<'a, K, V> {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> ValueMut<'a, K, V>
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(&mut *entry.get_mut());
+                Entry::Occupied(entry)
+            }
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K: Ord, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> Ref<'_, K> {
+        Ref::map(self.node.borrow(), |node| &node.item.key)
+    }
+
+    pub fn get(&self) -> Ref<'_, V> {
+        Ref::map(self.node.borrow(), |node| &node.item.value)
+    }
+
+    pub fn get_mut(&mut self) -> RefMut<'_, V> {
+        RefMut::map(self.node.borrow_mut(), |node| &mut node.item.value)
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(&mut *self.get_mut(), value)
+    }
+
+    pub fn into_mut(self) -> ValueMut<'a, K, V> {
+        ValueMut {
+            node: self.node,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn remove(self) -> V {
+        self.map.remove_node(self.node).1
+    }
+}
+
+impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
+    pub fn insert(self, value: V) -> ValueMut<'a, K, V> {
+        let node = self.map.list.push_node(MapEntry {
+            key: self.key,
+            value,
+        });
+        self.map.len += 1;
+
+        ValueMut {
+            node,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = DLMap::new();
+
+        assert_eq!(map.insert(3, "drei"), None);
+        assert_eq!(map.insert(1, "eins"), None);
+        assert_eq!(map.insert(2, "zwei"), None);
+        assert_eq!(map.insert(1, "EINS"), Some("eins"));
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(*map.get(&1).unwrap().get(), "EINS");
+        assert_eq!(*map.get(&2).unwrap().key(), 2);
+        assert!(map.get(&4).is_none());
+        assert!(map.contains_key(&3));
+
+        assert_eq!(map.remove(&2), Some("zwei"));
+        assert_eq!(map.remove(&2), None);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.keys(), vec![1, 3]);
+    }
+
+    #[test]
+    fn get_mut_changes_value() {
+        let mut map: DLMap<&str, Vec<i32>> = DLMap::new();
+        map.insert("b", vec![1]);
+
+        map.get_mut(&"b").unwrap().get_mut().push(2);
+        let old = map.get_mut(&"b").unwrap().set(vec![7]);
+
+        assert_eq!(old, vec![1, 2]);
+        assert_eq!(*map.get(&"b").unwrap().get(), vec![7]);
+    }
+
+    #[test]
+    fn entry_api() {
+        let mut map = DLMap::new();
+
+        for word in ["b", "a", "b", "c", "b", "a"] {
+            *map.entry(word).or_insert(0).get_mut() += 1;
+        }
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![("a", 2), ("b", 3), ("c", 1)]
+        );
+
+        map.entry("a").and_modify(|count| *count *= 10).or_default();
+        map.entry("d").and_modify(|count| *count *= 10).or_default();
+        assert_eq!(*map.get(&"a").unwrap().get(), 20);
+        assert_eq!(*map.get(&"d").unwrap().get(), 0);
+
+        match map.entry("c") {
+            Entry::Occupied(entry) => {
+                assert_eq!(*entry.key(), "c");
+                assert_eq!(entry.remove(), 1);
+            }
+            Entry::Vacant(_) => panic!("c muss vorhanden sein"),
+        }
+        match map.entry("e") {
+            Entry::Vacant(entry) => assert_eq!(*entry.key(), "e"),
+            Entry::Occupied(_) => panic!("e darf nicht vorhanden sein"),
+        }
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.keys(), vec!["a", "b", "d"]);
+    }
+
+    #[test]
+    fn ordered_iteration() {
+        let map: DLMap<i32, String> = [(5, "f"), (1, "b"), (3, "d"), (0, "a")]
+            .into_iter()
+            .map(|(k, v)| (k, v.to_string()))
+            .collect();
+
+        let keys: Vec<i32> = map.iter().map(|(k, _)| k).collect();
+        assert_eq!(keys, vec![0, 1, 3, 5]);
+
+        let mut pairs = map.into_iter();
+        assert_eq!(pairs.next(), Some((0, "a".to_string())));
+        assert_eq!(pairs.next_back(), Some((5, "f".to_string())));
+        assert_eq!(pairs.collect::<Vec<_>>().len(), 2);
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let mut map: DLMap<String, i32> = DLMap::new();
+        map.insert(String::from("b"), 2);
+        map.insert(String::from("a"), 1);
+
+        assert!(map.contains_key("a"));
+        assert_eq!(*map.get("b").unwrap().get(), 2);
+        *map.get_mut("a").unwrap().get_mut() += 10;
+        assert_eq!(map.remove("a"), Some(11));
+        assert_eq!(map.remove_entry("b"), Some((String::from("b"), 2)));
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn keys_without_ord_on_values() {
+        //V braucht kein Ord, auch keine Vergleichbarkeit
+        struct Payload(u8);
+
+        let mut map = DLMap::new();
+        map.insert(2, Payload(20));
+        map.insert(1, Payload(10));
+
+        assert_eq!(map.pop_first().map(|(k, v)| (k, v.0)), Some((1, 10)));
+        assert_eq!(map.pop_last().map(|(k, v)| (k, v.0)), Some((2, 20)));
+        assert!(map.is_empty());
+    }
+}
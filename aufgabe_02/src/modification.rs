@@ -0,0 +1,165 @@
+use alloc::rc::Rc;
+use core::cell::Cell;
+use core::fmt;
+
+use crate::DLList;
+
+/*Erkennen von Änderungen während der Iteration (fail-fast)
+
+Die Iteratoren der DLList leihen sich die Liste nicht aus, sondern halten selbst einen Zeiger auf
+den nächsten Knoten. Wird die Liste in der Zwischenzeit verändert, würde der Iterator still Werte
+überspringen oder doppelt ausgeben:
+
+    let mut iter = list.iter();   // 1 <-> 3 <-> 5
+    iter.next();                  // 1, nächster Knoten ist 3
+    list.pop_front();             // 3 <-> 5
+    list.push(2);                 // 2 <-> 3 <-> 5, die 2 würde nie ausgegeben
+
+Die Liste hat deshalb einen Änderungszähler, der bei jedem Ein- und Aushängen eines Knotens erhöht
+wird (wie modCount bei den Java-Collections). Jeder Iterator merkt sich beim Erstellen den Stand
+(ModificationCheck) und prüft ihn vor jedem Schritt. Hat sich der Zähler geändert, gibt try_next
+Err(ConcurrentModification) zurück, next löst eine Panik mit einer verständlichen Meldung aus.
+
+Reines Ändern eines Wertes (z.B. über ValueMut bei DLMap) verändert die Verkettung nicht und
+zählt deshalb nicht als Änderung.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConcurrentModification;
+
+impl fmt::Display for ConcurrentModification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ConcurrentModification: die Liste wurde während der Iteration verändert"
+        )
+    }
+}
+
+impl core::error::Error for ConcurrentModification {}
+
+//Gemerkter Stand des Änderungszählers einer Liste
+pub(crate) struct ModificationCheck {
+    modifications: Rc<Cell<usize>>,
+    expected: usize,
+}
+
+impl ModificationCheck {
+    //Stand eines beliebigen Änderungszählers merken (auch für Strukturen außer DLList)
+    pub(crate) fn new(modifications: &Rc<Cell<usize>>) -> Self {
+        Self {
+            modifications: modifications.clone(),
+            expected: modifications.get(),
+        }
+    }
+
+    pub(crate) fn check(&self) -> Result<(), ConcurrentModification> {
+        if self.modifications.get() == self.expected {
+            Ok(())
+        } else {
+            Err(ConcurrentModification)
+        }
+    }
+}
+
+impl<T> DLList<T> {
+    //Aktuellen Stand des Änderungszählers für einen Iterator merken
+    pub(crate) fn modification_check(&self) -> ModificationCheck {
+        ModificationCheck::new(&self.modifications)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Iter;
+    use crate::map::DLMap;
+
+    fn detects_change(mut iter: Iter<i32>) -> bool {
+        iter.try_next() == Err(ConcurrentModification)
+    }
+
+    fn filled_list() -> DLList<i32> {
+        [1, 3, 5, 7].into_iter().collect()
+    }
+
+    #[test]
+    fn unchanged_list_iterates() {
+        let dll = filled_list();
+        let mut iter = dll.iter();
+
+        assert_eq!(iter.try_next(), Ok(Some(1)));
+        assert_eq!(iter.try_next(), Ok(Some(3)));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![5, 7]);
+        assert_eq!(dll.iter().try_next(), Ok(Some(1)));
+    }
+
+    #[test]
+    fn push_during_iteration() {
+        let mut dll = filled_list();
+        let mut iter = dll.iter();
+        assert_eq!(iter.try_next(), Ok(Some(1)));
+
+        dll.push(2);
+        assert_eq!(iter.try_next(), Err(ConcurrentModification));
+        //Der Fehler bleibt bestehen, der Iterator läuft nicht einfach weiter
+        assert_eq!(iter.try_next(), Err(ConcurrentModification));
+
+        //Ein neuer Iterator sieht die geänderte Liste
+        assert_eq!(dll.iter().collect::<Vec<_>>(), vec![1, 2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn pop_of_next_node() {
+        let mut dll = filled_list();
+        let mut iter = dll.iter();
+        assert_eq!(iter.try_next(), Ok(Some(1)));
+
+        //Der Iterator zeigt auf die 3, der Knoten kann trotzdem ausgehängt werden
+        assert_eq!(dll.pop_front(), Some(1));
+        assert_eq!(dll.pop_front(), Some(3));
+        assert_eq!(iter.try_next(), Err(ConcurrentModification));
+    }
+
+    #[test]
+    fn other_mutations_are_detected() {
+        let mut dll = filled_list();
+
+        let iter = dll.iter();
+        dll.remove(&5);
+        assert!(detects_change(iter));
+
+        let iter = dll.iter();
+        *dll.peek_front_mut().unwrap().get_mut() = 4;
+        assert!(detects_change(iter));
+
+        let iter = dll.iter();
+        dll.drain(..5).for_each(drop);
+        assert!(detects_change(iter));
+    }
+
+    #[test]
+    #[should_panic(expected = "ConcurrentModification")]
+    fn next_panics() {
+        let mut dll = filled_list();
+        let mut iter = dll.iter();
+        iter.next();
+
+        dll.pop_back();
+        iter.next();
+    }
+
+    #[test]
+    fn map_iter() {
+        let mut map: DLMap<i32, &str> = [(1, "a"), (2, "b")].into_iter().collect();
+        let mut iter = map.iter();
+        assert_eq!(iter.try_next(), Ok(Some((1, "a"))));
+
+        //Werte ändern ist erlaubt, Einfügen nicht
+        *map.get_mut(&2).unwrap().get_mut() = "c";
+        assert_eq!(iter.try_next(), Ok(Some((2, "c"))));
+
+        let mut iter = map.iter();
+        map.insert(0, "z");
+        assert_eq!(iter.try_next(), Err(ConcurrentModification));
+    }
+}
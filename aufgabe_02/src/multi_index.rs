@@ -0,0 +1,580 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::borrow;
+use core::cell::{Cell, RefCell};
+use core::cmp::Ordering;
+
+use crate::modification::{ConcurrentModification, ModificationCheck};
+use crate::{Link, Node, WeakLink, compare, get_next, get_prev, set_next, set_prev, to_weak};
+
+/*Multi-Index-Liste (wie Boost.MultiIndex, nur klein)
+
+Ein Element soll gleichzeitig nach mehreren Kriterien sortiert sein, z.B. nach id und nach Zeit.
+Mit zwei DLLists müsste jeder Wert doppelt gespeichert und beim Löschen in beiden Listen gesucht
+werden. Hier gibt es jeden Wert nur einmal in einem Entry, jeder Index hat eine eigene sortierte
+Kette aus normalen Knoten (Node/Link wie bei DLList), die alle auf dasselbe Entry zeigen:
+
+    Index 0 (id):    head -> [id 1] <-> [id 2] <-> [id 3] <- tail
+    Index 1 (zeit):  head -> [t 10] <-> [t 20] <-> [t 30] <- tail
+                                 \        |        /
+                          Entry {id 2, t 10}, Entry {id 3, t 20}, ...
+
+Das Entry kennt seinen Knoten in jeder Kette (Weak), dadurch kann es nach dem Finden über einen
+Index direkt aus allen anderen Ketten ausgehängt werden, ohne dort noch einmal zu suchen.
+
+Ein Index wird mit add_index und einer Schlüsselfunktion angelegt. Der zurückgegebene Index<T, K>
+wird bei allen Abfragen mitgegeben, damit z.B. nach dem Schlüssel K gesucht werden kann:
+
+    let by_id = list.add_index(|p: &Person| p.id);
+    list.remove(&by_id, &3);
+
+Elemente mit gleichem Schlüssel stehen in jeder Kette in Einfügereihenfolge, egal ob sie mit push
+eingefügt oder von add_index nachträglich einsortiert werden. remove/get finden also das älteste.
+
+Wie Iter bei DLList hält MultiIter nur Weak-Zeiger und prüft den Änderungszähler. Wird die Liste
+während der Iteration verändert, gibt try_next Err(ConcurrentModification) zurück, next eine Panik.
+*/
+type EntryLink<T> = Link<Rc<Entry<T>>>;
+type WeakEntryLink<T> = WeakLink<Rc<Entry<T>>>;
+type Compare<T> = Box<dyn Fn(&Entry<T>, &Entry<T>) -> Ordering>;
+
+struct Entry<T> {
+    value: T,
+    //Laufende Nummer beim Einfügen, entscheidet bei gleichem Schlüssel über die Reihenfolge
+    sequence: usize,
+    //Knoten des Entrys in jeder Kette, nodes[i] gehört zur Kette von Index i
+    nodes: RefCell<Vec<WeakEntryLink<T>>>,
+}
+
+//Eine sortierte Kette mit eigener Vergleichsfunktion
+struct Chain<T> {
+    head: Option<EntryLink<T>>,
+    tail: Option<EntryLink<T>>,
+    compare: Compare<T>,
+}
+
+//Verweis auf einen Index, nur für die Liste gültig, die ihn mit add_index erstellt hat (sonst Panik)
+pub struct Index<T, K> {
+    //Kennung der Liste, zu der der Index gehört (siehe MultiIndexList::owner)
+    owner: Rc<()>,
+    id: usize,
+    key: Rc<dyn Fn(&T) -> K>,
+}
+
+impl<T, K> Clone for Index<T, K> {
+    fn clone(&self) -> Self {
+        Self {
+            owner: self.owner.clone(),
+            id: self.id,
+            key: self.key.clone(),
+        }
+    }
+}
+
+pub struct MultiIndexList<T> {
+    chains: Vec<Chain<T>>,
+    len: usize,
+    //Nummer für das nächste Entry
+    next_sequence: usize,
+    //Änderungszähler wie bei DLList (siehe modification.rs)
+    modifications: Rc<Cell<usize>>,
+    //Eindeutige Kennung der Liste, jeder Index hält eine Kopie. Ein Index einer anderen Liste
+    //hätte sonst eine gültige id, würde aber eine nach einem anderen Schlüssel sortierte Kette lesen.
+    owner: Rc<()>,
+}
+
+fn entry_of<T>(node: &EntryLink<T>) -> Rc<Entry<T>> {
+    node.borrow().item.clone()
+}
+
+impl<T> MultiIndexList<T> {
+    pub fn new() -> Self {
+        Self {
+            chains: Vec::new(),
+            len: 0,
+            next_sequence: 0,
+            modifications: Rc::new(Cell::new(0)),
+            owner: Rc::new(()),
+        }
+    }
+
+    //Neuen Index anlegen, vorhandene Elemente werden in die neue Kette einsortiert
+    pub fn add_index<K, F>(&mut self, key: F) -> Index<T, K>
+    where
+        T: 'static,
+        K: Ord + 'static,
+        F: Fn(&T) -> K + 'static,
+    {
+        let key: Rc<dyn Fn(&T) -> K> = Rc::new(key);
+        let compare_key = key.clone();
+        let id = self.chains.len();
+
+        let chain = Chain {
+            head: None,
+            tail: None,
+            compare: Box::new(move |a: &Entry<T>, b: &Entry<T>| {
+                compare_key(&a.value)
+                    .cmp(&compare_key(&b.value))
+                    .then(a.sequence.cmp(&b.sequence))
+            }),
+        };
+
+        //Alle Entries über die erste Kette einsammeln und mit derselben Vergleichsfunktion sortieren
+        let mut entries = Vec::with_capacity(self.len);
+        let mut current = self.chains.first().and_then(|chain| chain.head.clone());
+        while let Some(node) = current {
+            current = get_next(&node);
+            entries.push(entry_of(&node));
+        }
+        entries.sort_by(|a, b| (chain.compare)(a, b));
+
+        self.chains.push(chain);
+        for entry in entries {
+            let node = Self::new_node(&entry);
+            let tail = self.chains[id].tail.clone();
+            self.link(id, &node, tail, None);
+        }
+
+        Index {
+            owner: self.owner.clone(),
+            id,
+            key,
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        assert!(
+            !self.chains.is_empty(),
+            "vor dem ersten push muss mit add_index ein Index angelegt werden"
+        );
+
+        let entry = Rc::new(Entry {
+            value,
+            sequence: self.next_sequence,
+            nodes: RefCell::new(Vec::with_capacity(self.chains.len())),
+        });
+        self.next_sequence += 1;
+
+        //In jeder Kette vor dem ersten größeren Knoten einhängen. Das neue Entry hat die höchste
+        //Nummer, gleiche Schlüssel bleiben damit in Einfügereihenfolge (wie bei add_index).
+        for index in 0..self.chains.len() {
+            let node = Self::new_node(&entry);
+
+            let mut next = self.chains[index].head.clone();
+            while let Some(candidate) = next.clone() {
+                let ordering = (self.chains[index].compare)(&entry_of(&candidate), &entry);
+                if ordering == Ordering::Greater {
+                    break;
+                }
+                next = get_next(&candidate);
+            }
+
+            let prev = match &next {
+                Some(node_after) => get_prev(node_after).and_then(|weak| weak.upgrade()),
+                None => self.chains[index].tail.clone(),
+            };
+            self.link(index, &node, prev, next);
+        }
+
+        self.len += 1;
+        self.modified();
+    }
+
+    pub fn contains_key<K, Q: Ord + ?Sized>(&self, index: &Index<T, K>, key: &Q) -> bool
+    where
+        K: borrow::Borrow<Q>,
+    {
+        self.find_entry(index, key).is_some()
+    }
+
+    //Entfernt das erste Element mit dem Schlüssel aus allen Ketten
+    pub fn remove<K, Q: Ord + ?Sized>(&mut self, index: &Index<T, K>, key: &Q) -> Option<T>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        let entry = self.find_entry(index, key)?;
+        Some(self.remove_entry(entry))
+    }
+
+    pub fn pop_front<K>(&mut self, index: &Index<T, K>) -> Option<T> {
+        let entry = entry_of(self.chain(index).head.as_ref()?);
+        Some(self.remove_entry(entry))
+    }
+
+    pub fn pop_back<K>(&mut self, index: &Index<T, K>) -> Option<T> {
+        let entry = entry_of(self.chain(index).tail.as_ref()?);
+        Some(self.remove_entry(entry))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn index_count(&self) -> usize {
+        self.chains.len()
+    }
+
+    fn chain<K>(&self, index: &Index<T, K>) -> &Chain<T> {
+        assert!(
+            Rc::ptr_eq(&index.owner, &self.owner),
+            "der Index gehört nicht zu dieser Liste"
+        );
+        &self.chains[index.id]
+    }
+
+    fn modified(&self) {
+        self.modifications.set(self.modifications.get() + 1);
+    }
+
+    //Neuen Knoten für das Entry anlegen und beim Entry eintragen
+    fn new_node(entry: &Rc<Entry<T>>) -> EntryLink<T> {
+        let node = Rc::new(RefCell::new(Node::new(entry.clone())));
+        entry.nodes.borrow_mut().push(Rc::downgrade(&node));
+        node
+    }
+
+    fn find_entry<K, Q: Ord + ?Sized>(&self, index: &Index<T, K>, key: &Q) -> Option<Rc<Entry<T>>>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        let mut current = self.chain(index).head.clone();
+
+        while let Some(node) = current {
+            let entry = entry_of(&node);
+            match compare(&(index.key)(&entry.value), key) {
+                Ordering::Equal => return Some(entry),
+                Ordering::Greater => return None,
+                Ordering::Less => {}
+            }
+            current = get_next(&node);
+        }
+
+        None
+    }
+
+    //Knoten in Kette index zwischen prev und next einhängen
+    fn link(
+        &mut self,
+        index: usize,
+        node: &EntryLink<T>,
+        prev: Option<EntryLink<T>>,
+        next: Option<EntryLink<T>>,
+    ) {
+        set_prev(node, to_weak(&prev));
+        set_next(node, next.clone());
+
+        let chain = &mut self.chains[index];
+        match &prev {
+            Some(node_before) => set_next(node_before, Some(node.clone())),
+            None => chain.head = Some(node.clone()),
+        }
+        match &next {
+            Some(node_after) => set_prev(node_after, Some(Rc::downgrade(node))),
+            None => chain.tail = Some(node.clone()),
+        }
+    }
+
+    //Knoten aus Kette index aushängen
+    fn unlink(&mut self, index: usize, node: &EntryLink<T>) {
+        let prev = get_prev(node).and_then(|weak| weak.upgrade());
+        let next = node.borrow_mut().next.take();
+        set_prev(node, None);
+
+        let chain = &mut self.chains[index];
+        match &prev {
+            Some(node_before) => set_next(node_before, next.clone()),
+            None => chain.head = next.clone(),
+        }
+        match &next {
+            Some(node_after) => set_prev(node_after, to_weak(&prev)),
+            None => chain.tail = prev,
+        }
+    }
+
+    //Entry aus allen Ketten aushängen und den Wert herausholen
+    fn remove_entry(&mut self, entry: Rc<Entry<T>>) -> T {
+        let nodes = entry.nodes.take();
+        for (index, weak) in nodes.iter().enumerate() {
+            let node = weak.upgrade().unwrap();
+            self.unlink(index, &node);
+        }
+        self.len -= 1;
+        self.modified();
+
+        //Die ausgehängten Knoten sind freigegeben und Iteratoren halten nur Weak-Zeiger,
+        //die lokale Referenz ist also die letzte
+        Rc::try_unwrap(entry).ok().unwrap().value
+    }
+}
+
+impl<T: Clone> MultiIndexList<T> {
+    //Werte in der Reihenfolge des Index (als Kopie, wie Iter bei DLList)
+    pub fn iter<K>(&self, index: &Index<T, K>) -> MultiIter<T> {
+        MultiIter {
+            next: to_weak(&self.chain(index).head),
+            check: ModificationCheck::new(&self.modifications),
+        }
+    }
+
+    //Erstes Element mit dem Schlüssel
+    pub fn get<K, Q: Ord + ?Sized>(&self, index: &Index<T, K>, key: &Q) -> Option<T>
+    where
+        K: borrow::Borrow<Q>,
+    {
+        let entry = self.find_entry(index, key)?;
+        Some(entry.value.clone())
+    }
+
+    pub fn front<K>(&self, index: &Index<T, K>) -> Option<T> {
+        let node = self.chain(index).head.as_ref()?;
+        Some(entry_of(node).value.clone())
+    }
+
+    pub fn back<K>(&self, index: &Index<T, K>) -> Option<T> {
+        let node = self.chain(index).tail.as_ref()?;
+        Some(entry_of(node).value.clone())
+    }
+}
+
+impl<T> Default for MultiIndexList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for MultiIndexList<T> {
+    fn drop(&mut self) {
+        //Jede Kette Knoten für Knoten auflösen, damit lange Ketten nicht rekursiv freigegeben werden
+        for chain in &mut self.chains {
+            chain.tail = None;
+            let mut current = chain.head.take();
+            while let Some(node) = current {
+                current = node.borrow_mut().next.take();
+            }
+        }
+    }
+}
+
+pub struct MultiIter<T> {
+    next: Option<WeakEntryLink<T>>,
+    check: ModificationCheck,
+}
+
+impl<T: Clone> MultiIter<T> {
+    pub fn try_next(&mut self) -> Result<Option<T>, ConcurrentModification> {
+        self.check.check()?;
+
+        let node = match self.next.take() {
+            None => return Ok(None),
+            Some(weak) => weak.upgrade().ok_or(ConcurrentModification)?,
+        };
+        self.next = to_weak(&get_next(&node));
+        Ok(Some(entry_of(&node).value.clone()))
+    }
+}
+
+impl<T: Clone> Iterator for MultiIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match self.try_next() {
+            Ok(item) => item,
+            Err(err) => panic!("{}", err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Event {
+        id: u32,
+        time: u32,
+        name: &'static str,
+    }
+
+    fn event(id: u32, time: u32, name: &'static str) -> Event {
+        Event { id, time, name }
+    }
+
+    fn ids(list: &MultiIndexList<Event>, index: &Index<Event, impl Ord>) -> Vec<u32> {
+        list.iter(index).map(|event| event.id).collect()
+    }
+
+    type Indexes = (
+        Index<Event, u32>,
+        Index<Event, u32>,
+        Index<Event, &'static str>,
+    );
+
+    fn filled_list() -> (MultiIndexList<Event>, Indexes) {
+        let mut list = MultiIndexList::new();
+        let by_id = list.add_index(|event: &Event| event.id);
+        let by_time = list.add_index(|event: &Event| event.time);
+        let by_name = list.add_index(|event: &Event| event.name);
+
+        list.push(event(3, 20, "c"));
+        list.push(event(1, 30, "b"));
+        list.push(event(4, 5, "a"));
+        list.push(event(2, 10, "d"));
+
+        (list, (by_id, by_time, by_name))
+    }
+
+    #[test]
+    fn every_index_is_sorted() {
+        let (list, (by_id, by_time, by_name)) = filled_list();
+
+        assert_eq!(ids(&list, &by_id), vec![1, 2, 3, 4]);
+        assert_eq!(ids(&list, &by_time), vec![4, 2, 3, 1]);
+        assert_eq!(ids(&list, &by_name), vec![4, 1, 3, 2]);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.index_count(), 3);
+    }
+
+    #[test]
+    fn remove_through_one_index_unlinks_everywhere() {
+        let (mut list, (by_id, by_time, by_name)) = filled_list();
+
+        assert_eq!(list.remove(&by_time, &20), Some(event(3, 20, "c")));
+        assert_eq!(list.remove(&by_time, &20), None);
+        assert!(!list.contains_key(&by_id, &3));
+        assert_eq!(ids(&list, &by_id), vec![1, 2, 4]);
+        assert_eq!(ids(&list, &by_name), vec![4, 1, 2]);
+
+        assert_eq!(list.pop_front(&by_name).map(|event| event.id), Some(4));
+        assert_eq!(list.pop_back(&by_id).map(|event| event.id), Some(2));
+        assert_eq!(ids(&list, &by_time), vec![1]);
+        assert_eq!(list.len(), 1);
+
+        assert_eq!(list.pop_back(&by_time).map(|event| event.id), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.front(&by_id), None);
+        assert_eq!(list.back(&by_name), None);
+    }
+
+    #[test]
+    fn lookup_and_ends() {
+        let (list, (by_id, by_time, by_name)) = filled_list();
+
+        assert_eq!(list.get(&by_name, &"b"), Some(event(1, 30, "b")));
+        assert_eq!(list.get(&by_id, &7), None);
+        assert_eq!(list.front(&by_time), Some(event(4, 5, "a")));
+        assert_eq!(list.back(&by_time), Some(event(1, 30, "b")));
+    }
+
+    #[test]
+    fn equal_keys_keep_all_elements() {
+        let mut list = MultiIndexList::new();
+        let by_time = list.add_index(|event: &Event| event.time);
+        list.push(event(1, 10, "a"));
+        list.push(event(2, 10, "b"));
+        list.push(event(3, 5, "c"));
+
+        //Gleiche Schlüssel in Einfügereihenfolge, remove findet das älteste
+        assert_eq!(ids(&list, &by_time), vec![3, 1, 2]);
+        assert_eq!(list.remove(&by_time, &10).map(|event| event.id), Some(1));
+        assert_eq!(list.remove(&by_time, &10).map(|event| event.id), Some(2));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn equal_keys_same_order_in_push_and_add_index() {
+        let events = [(4, 10), (1, 10), (3, 5), (2, 10)];
+
+        //Zeit-Index vor dem Einfügen angelegt
+        let mut early = MultiIndexList::new();
+        let early_time = early.add_index(|event: &Event| event.time);
+        //Zeit-Index erst danach angelegt
+        let mut late = MultiIndexList::new();
+        late.add_index(|event: &Event| event.id);
+
+        for (id, time) in events {
+            early.push(event(id, time, "x"));
+            late.push(event(id, time, "x"));
+        }
+        let late_time = late.add_index(|event: &Event| event.time);
+
+        assert_eq!(ids(&early, &early_time), vec![3, 4, 1, 2]);
+        assert_eq!(ids(&late, &late_time), vec![3, 4, 1, 2]);
+    }
+
+    #[test]
+    fn pop_while_iterating() {
+        let (mut list, (by_id, by_time, _)) = filled_list();
+
+        let mut iter = list.iter(&by_id);
+        assert_eq!(
+            iter.try_next().map(|event| event.map(|e| e.id)),
+            Ok(Some(1))
+        );
+        //Darf nicht abstürzen, obwohl der Iterator noch lebt
+        assert_eq!(list.pop_front(&by_id).map(|event| event.id), Some(1));
+        assert_eq!(list.pop_front(&by_time).map(|event| event.id), Some(4));
+        assert_eq!(iter.try_next(), Err(ConcurrentModification));
+        assert_eq!(ids(&list, &by_id), vec![2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ConcurrentModification")]
+    fn next_panics_after_change() {
+        let (mut list, (by_id, _, _)) = filled_list();
+
+        let mut iter = list.iter(&by_id);
+        list.pop_back(&by_id);
+        iter.next();
+    }
+
+    #[test]
+    fn add_index_later() {
+        let mut list = MultiIndexList::new();
+        let by_id = list.add_index(|event: &Event| event.id);
+        for (id, time) in [(5, 1), (2, 9), (7, 4), (1, 6)] {
+            list.push(event(id, time, "x"));
+        }
+
+        let by_time = list.add_index(|event: &Event| event.time);
+        assert_eq!(ids(&list, &by_time), vec![5, 7, 1, 2]);
+
+        //Neue Elemente kommen auch in die neue Kette
+        list.push(event(3, 5, "y"));
+        assert_eq!(ids(&list, &by_time), vec![5, 7, 3, 1, 2]);
+        assert_eq!(ids(&list, &by_id), vec![1, 2, 3, 5, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "der Index gehört nicht zu dieser Liste")]
+    fn foreign_index() {
+        let (list, _) = filled_list();
+        let (_, (_, by_time, _)) = filled_list();
+
+        //Gleiche id wie by_time in list, aber aus einer anderen Liste
+        list.contains_key(&by_time, &20);
+    }
+
+    #[test]
+    #[should_panic(expected = "add_index")]
+    fn push_without_index() {
+        let mut list = MultiIndexList::new();
+        list.push(1);
+    }
+
+    #[test]
+    fn drop_long_list() {
+        let mut list = MultiIndexList::new();
+        list.add_index(|wert: &u32| *wert);
+        let reverse = list.add_index(|wert: &u32| std::cmp::Reverse(*wert));
+        //Beim Anhängen an das Ende von Index 0 ist der Wert in Index 1 immer vorne
+        for wert in 0..5_000 {
+            list.push(wert);
+        }
+        assert_eq!(list.front(&reverse), Some(4_999));
+    }
+}
@@ -0,0 +1,275 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::{DLList, Link, get_next, get_prev};
+
+/*Beobachter (Observer) für Änderungen an der Liste
+
+Um abgeleitete Strukturen (z.B. einen zweiten Index, ein Protokoll oder Zähler) synchron zur
+DLList zu halten, können Beobachter angemeldet werden. Sie werden bei jedem Einfügen und Entfernen
+eines Knotens aufgerufen und bekommen den betroffenen Wert und seine Nachbarn:
+
+    list.subscribe(|change: &ListChange<'_, i32>| println!("{:?}", change.kind));
+
+    1 <-> 5         push(3)  ->  Inserted { value: 3, prev: Some(1), next: Some(5) }
+    1 <-> 3 <-> 5   remove(3) -> Removed  { value: 3, prev: Some(1), next: Some(5) }
+
+Die Nachbarn sind beim Einfügen die neuen, beim Entfernen die bisherigen Nachbarn.
+Gemeldet wird alles, was die Verkettung ändert: push, pop_front/pop_back, remove, drain,
+extract_if, Transaktionen (auch der Rollback) usw. Wird ein Wert über PeekMut geändert, kommt beim
+ersten get_mut/set ein Removed mit dem alten Wert und beim Drop des Guards ein Inserted mit dem
+neuen Wert und den neuen Nachbarn.
+
+Ohne angemeldete Beobachter kostet das nur eine Abfrage auf einen leeren Vec.
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChangeKind {
+    Inserted,
+    Removed,
+}
+
+#[derive(Debug)]
+pub struct ListChange<'a, T> {
+    pub kind: ChangeKind,
+    pub value: &'a T,
+    pub prev: Option<&'a T>,
+    pub next: Option<&'a T>,
+}
+
+pub trait ListObserver<T> {
+    fn on_change(&mut self, change: &ListChange<'_, T>);
+}
+
+//Closures können direkt als Beobachter benutzt werden
+impl<T, F: FnMut(&ListChange<'_, T>)> ListObserver<T> for F {
+    fn on_change(&mut self, change: &ListChange<'_, T>) {
+        self(change)
+    }
+}
+
+//Wird beim Anmelden zurückgegeben und zum Abmelden gebraucht
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ObserverId(usize);
+
+pub(crate) struct Observers<T> {
+    list: Vec<(ObserverId, Box<dyn ListObserver<T>>)>,
+    next_id: usize,
+}
+
+impl<T> Observers<T> {
+    pub(crate) fn none() -> Self {
+        Self {
+            list: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    //Alle Beobachter über einen eingefügten bzw. gleich entfernten Knoten informieren
+    pub(crate) fn notify(
+        &mut self,
+        kind: ChangeKind,
+        node: &Link<T>,
+        prev: Option<&Link<T>>,
+        next: Option<&Link<T>>,
+    ) {
+        if self.list.is_empty() {
+            return;
+        }
+
+        let value = node.borrow();
+        let prev = prev.map(|node| node.borrow());
+        let next = next.map(|node| node.borrow());
+        let change = ListChange {
+            kind,
+            value: &value.item,
+            prev: prev.as_ref().map(|node| &node.item),
+            next: next.as_ref().map(|node| &node.item),
+        };
+
+        for (_, observer) in self.list.iter_mut() {
+            observer.on_change(&change);
+        }
+    }
+}
+
+impl<T> DLList<T> {
+    pub fn subscribe<O: ListObserver<T> + 'static>(&mut self, observer: O) -> ObserverId {
+        let id = ObserverId(self.observers.next_id);
+        self.observers.next_id += 1;
+        self.observers.list.push((id, Box::new(observer)));
+        id
+    }
+
+    //Gibt false zurück, wenn es keinen Beobachter mit der id (mehr) gibt
+    pub fn unsubscribe(&mut self, id: ObserverId) -> bool {
+        let before = self.observers.list.len();
+        self.observers.list.retain(|(other, _)| *other != id);
+        self.observers.list.len() != before
+    }
+
+    pub fn observer_count(&self) -> usize {
+        self.observers.list.len()
+    }
+
+    //Beobachter über einen Knoten informieren, die Nachbarn werden aus dem Knoten gelesen
+    pub(crate) fn notify_linked(&mut self, kind: ChangeKind, node: &Link<T>) {
+        let prev = get_prev(node).and_then(|weak| weak.upgrade());
+        let next = get_next(node);
+        self.observers
+            .notify(kind, node, prev.as_ref(), next.as_ref());
+    }
+
+    //Verkettung ändern, ohne dass die Beobachter etwas davon mitbekommen
+    pub(crate) fn without_observers<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let observers = core::mem::replace(&mut self.observers, Observers::none());
+        let result = f(self);
+        self.observers = observers;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    type Log = Rc<RefCell<Vec<(ChangeKind, i32, Option<i32>, Option<i32>)>>>;
+
+    //Beobachter, der alle Änderungen mit Nachbarn protokolliert
+    fn logging_list(values: &[i32]) -> (DLList<i32>, Log) {
+        let mut dll: DLList<i32> = values.iter().copied().collect();
+        let log = Log::default();
+
+        let target = log.clone();
+        dll.subscribe(move |change: &ListChange<'_, i32>| {
+            target.borrow_mut().push((
+                change.kind,
+                *change.value,
+                change.prev.copied(),
+                change.next.copied(),
+            ));
+        });
+
+        (dll, log)
+    }
+
+    #[test]
+    fn push_reports_neighbours() {
+        let (mut dll, log) = logging_list(&[1, 5]);
+
+        dll.push(3);
+        dll.push(0);
+        dll.push(9);
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                (ChangeKind::Inserted, 3, Some(1), Some(5)),
+                (ChangeKind::Inserted, 0, None, Some(1)),
+                (ChangeKind::Inserted, 9, Some(5), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn removals_report_old_neighbours() {
+        let (mut dll, log) = logging_list(&[1, 3, 5, 7]);
+
+        assert_eq!(dll.remove(&3), Some(3));
+        assert_eq!(dll.pop_front(), Some(1));
+        assert_eq!(dll.pop_back(), Some(7));
+        assert_eq!(dll.pop_back(), Some(5));
+
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                (ChangeKind::Removed, 3, Some(1), Some(5)),
+                (ChangeKind::Removed, 1, None, Some(5)),
+                (ChangeKind::Removed, 7, Some(5), None),
+                (ChangeKind::Removed, 5, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn drain_reports_each_value() {
+        let (mut dll, log) = logging_list(&[1, 3, 5, 7]);
+
+        assert_eq!(dll.drain(2..6).collect::<Vec<_>>(), vec![3, 5]);
+        assert_eq!(
+            *log.borrow(),
+            vec![
+                (ChangeKind::Removed, 3, Some(1), Some(5)),
+                (ChangeKind::Removed, 5, Some(1), Some(7)),
+            ]
+        );
+    }
+
+    //Zweiter Index: Anzahl je Wert, wird nur über die Beobachter gepflegt
+    struct CountIndex(Rc<RefCell<HashMap<i32, usize>>>);
+
+    impl ListObserver<i32> for CountIndex {
+        fn on_change(&mut self, change: &ListChange<'_, i32>) {
+            let mut counts = self.0.borrow_mut();
+            match change.kind {
+                ChangeKind::Inserted => *counts.entry(*change.value).or_default() += 1,
+                ChangeKind::Removed => {
+                    let count = counts.get_mut(change.value).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        counts.remove(change.value);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn secondary_index_stays_in_sync() {
+        let counts = Rc::new(RefCell::new(HashMap::new()));
+        let mut dll = DLList::new();
+        dll.subscribe(CountIndex(counts.clone()));
+
+        for wert in [4, 2, 4, 8, 2, 4] {
+            dll.push(wert);
+        }
+        dll.remove(&2);
+        dll.extract_if(|wert| *wert > 4).for_each(drop);
+        *dll.peek_front_mut().unwrap().get_mut() = 5;
+
+        //Abgebrochene Transaktion: der Rollback wird ebenfalls gemeldet
+        let result = dll.transaction(|tx| {
+            tx.push(1);
+            tx.pop_back();
+            Err::<(), _>("abbrechen")
+        });
+        assert!(result.is_err());
+
+        let mut expected = HashMap::new();
+        for wert in dll.iter() {
+            *expected.entry(wert).or_default() += 1;
+        }
+        assert_eq!(*counts.borrow(), expected);
+        assert_eq!(dll.to_vec(), vec![4, 4, 4, 5]);
+    }
+
+    #[test]
+    fn unsubscribe() {
+        let (mut dll, log) = logging_list(&[]);
+        let counter = Rc::new(RefCell::new(0));
+        let target = counter.clone();
+        let id = dll.subscribe(move |_: &ListChange<'_, i32>| *target.borrow_mut() += 1);
+        assert_eq!(dll.observer_count(), 2);
+
+        dll.push(1);
+        assert!(dll.unsubscribe(id));
+        assert!(!dll.unsubscribe(id));
+        dll.push(2);
+
+        assert_eq!(*counter.borrow(), 1);
+        assert_eq!(log.borrow().len(), 2);
+        assert_eq!(dll.observer_count(), 1);
+    }
+}
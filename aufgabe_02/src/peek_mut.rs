@@ -0,0 +1,258 @@
+use core::borrow;
+use core::cell::{Ref, RefMut};
+
+use crate::observer::ChangeKind;
+use crate::{DLList, Link, get_next, get_prev};
+
+/*Veränderbarer Zugriff mit automatischem Neu-Sortieren
+
+Ein Wert in der Liste darf nicht einfach geändert werden, sonst stimmt die Sortierung nicht mehr.
+Wie bei BinaryHeap::peek_mut gibt es deshalb einen Guard (PeekMut), über den der Wert geändert
+werden kann. Wird der Guard gedroppt, wird der Knoten entlang prev/next so weit nach links oder
+rechts verschoben, bis die Liste wieder sortiert ist:
+
+    1 <-> 4 <-> [5] <-> 8        5 wird zu 2        1 <-> [2] <-> 4 <-> 8
+
+Der Knoten selbst bleibt erhalten, es wird nur umgehängt (kein neues push).
+Da der Wert in einer RefCell liegt, gibt der Guard Ref/RefMut zurück (wie bei DLMap).
+
+Beobachter (observer.rs) bekommen beim ersten veränderbaren Zugriff ein Removed mit dem alten Wert
+und beim Drop ein Inserted mit dem neuen Wert, das Umhängen dazwischen wird nicht gemeldet.
+*/
+pub struct PeekMut<'a, T: Ord> {
+    list: &'a mut DLList<T>,
+    node: Option<Link<T>>,
+    //true, sobald den Beobachtern das Entfernen des alten Wertes gemeldet wurde
+    notified: bool,
+}
+
+impl<T: Ord> PeekMut<'_, T> {
+    pub fn get(&self) -> Ref<'_, T> {
+        Ref::map(self.node().borrow(), |node| &node.item)
+    }
+
+    pub fn get_mut(&mut self) -> RefMut<'_, T> {
+        if !self.notified {
+            self.notified = true;
+            let node = self.node().clone();
+            self.list.notify_linked(ChangeKind::Removed, &node);
+        }
+        RefMut::map(self.node().borrow_mut(), |node| &mut node.item)
+    }
+
+    //Wert ersetzen, gibt den alten Wert zurück
+    pub fn set(&mut self, wert: T) -> T {
+        core::mem::replace(&mut *self.get_mut(), wert)
+    }
+
+    //Element aus der Liste entfernen statt neu einzusortieren
+    pub fn pop(mut self) -> T {
+        let node = self.node.take().unwrap();
+        if self.notified {
+            self.list.without_observers(|list| list.unlink_node(&node));
+        } else {
+            self.list.unlink_node(&node);
+        }
+        self.list.pool.free(node)
+    }
+
+    fn node(&self) -> &Link<T> {
+        //node ist nur nach pop None, danach gibt es den Guard nicht mehr
+        self.node.as_ref().unwrap()
+    }
+}
+
+impl<T: Ord> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        if let Some(node) = self.node.take() {
+            if self.notified {
+                self.list.without_observers(|list| list.resort_node(&node));
+                self.list.notify_linked(ChangeKind::Inserted, &node);
+            } else {
+                self.list.resort_node(&node);
+            }
+        }
+    }
+}
+
+impl<T: Ord> DLList<T> {
+    pub fn peek_front_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        let node = self.head.clone()?;
+        Some(PeekMut {
+            list: self,
+            node: Some(node),
+            notified: false,
+        })
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        let node = self.tail.clone()?;
+        Some(PeekMut {
+            list: self,
+            node: Some(node),
+            notified: false,
+        })
+    }
+
+    //Element an Position index (0 = kleinster Wert)
+    pub fn get_mut(&mut self, index: usize) -> Option<PeekMut<'_, T>> {
+        let mut node = self.head.clone()?;
+        for _ in 0..index {
+            node = get_next(&node)?;
+        }
+
+        Some(PeekMut {
+            list: self,
+            node: Some(node),
+            notified: false,
+        })
+    }
+
+    //Erstes Element mit dem gesuchten Wert
+    pub fn find_mut<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<PeekMut<'_, T>>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let node = self.find_node(element)?;
+        Some(PeekMut {
+            list: self,
+            node: Some(node),
+            notified: false,
+        })
+    }
+
+    //Knoten nach einer Änderung nach links oder rechts verschieben, bis die Liste wieder sortiert ist
+    fn resort_node(&mut self, node: &Link<T>) {
+        let prev = get_prev(node).and_then(|weak| weak.upgrade());
+        let next = get_next(node);
+
+        let too_small = match &prev {
+            Some(node_before) => node_before.borrow().item > node.borrow().item,
+            None => false,
+        };
+        let too_large = match &next {
+            Some(node_after) => node_after.borrow().item < node.borrow().item,
+            None => false,
+        };
+
+        if too_small {
+            //Nach links laufen, bis ein Knoten <= dem Wert kommt, und dahinter einhängen
+            let mut before = prev;
+            while let Some(candidate) = before.clone() {
+                if candidate.borrow().item <= node.borrow().item {
+                    break;
+                }
+                before = get_prev(&candidate).and_then(|weak| weak.upgrade());
+            }
+
+            self.unlink_node(node);
+            let after = match &before {
+                Some(node_before) => get_next(node_before),
+                None => self.head.clone(),
+            };
+            self.link_node(node, before, after);
+        } else if too_large {
+            //Nach rechts laufen, bis ein Knoten >= dem Wert kommt, und davor einhängen
+            let mut after = next;
+            while let Some(candidate) = after.clone() {
+                if candidate.borrow().item >= node.borrow().item {
+                    break;
+                }
+                after = get_next(&candidate);
+            }
+
+            self.unlink_node(node);
+            let before = match &after {
+                Some(node_after) => get_prev(node_after).and_then(|weak| weak.upgrade()),
+                None => self.tail.clone(),
+            };
+            self.link_node(node, before, after);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::nodes;
+    use std::rc::Rc;
+
+    fn filled_list() -> DLList<i32> {
+        [1, 4, 5, 8, 12].into_iter().collect()
+    }
+
+    fn values(list: &DLList<i32>) -> Vec<i32> {
+        //nodes prüft dabei auch prev-Zeiger und tail
+        nodes(list).iter().map(|node| node.borrow().item).collect()
+    }
+
+    #[test]
+    fn decrease_moves_left() {
+        let mut dll = filled_list();
+
+        *dll.find_mut(&5).unwrap().get_mut() = 2;
+        assert_eq!(values(&dll), vec![1, 2, 4, 8, 12]);
+
+        //Ganz an den Anfang
+        dll.peek_back_mut().unwrap().set(0);
+        assert_eq!(values(&dll), vec![0, 1, 2, 4, 8]);
+    }
+
+    #[test]
+    fn increase_moves_right() {
+        let mut dll = filled_list();
+
+        *dll.peek_front_mut().unwrap().get_mut() += 6;
+        assert_eq!(values(&dll), vec![4, 5, 7, 8, 12]);
+
+        //Ganz ans Ende
+        dll.get_mut(1).unwrap().set(100);
+        assert_eq!(values(&dll), vec![4, 7, 8, 12, 100]);
+    }
+
+    #[test]
+    fn unchanged_order_stays() {
+        let mut dll = filled_list();
+        let before = nodes(&dll);
+
+        {
+            let mut guard = dll.get_mut(2).unwrap();
+            assert_eq!(*guard.get(), 5);
+            *guard.get_mut() = 6;
+        }
+
+        let after = nodes(&dll);
+        for (a, b) in before.iter().zip(&after) {
+            assert!(Rc::ptr_eq(a, b));
+        }
+        drop((before, after));
+        assert_eq!(dll.to_vec(), vec![1, 4, 6, 8, 12]);
+    }
+
+    #[test]
+    fn same_node_is_moved() {
+        let mut dll = filled_list();
+        let node = dll.head.clone().unwrap();
+
+        dll.peek_front_mut().unwrap().set(9);
+
+        //Der Knoten wurde nur umgehängt und steht jetzt zwischen 8 und 12
+        let moved = get_next(&dll.find_node(&8).unwrap()).unwrap();
+        assert!(Rc::ptr_eq(&node, &moved));
+        drop((node, moved));
+        assert_eq!(values(&dll), vec![4, 5, 8, 9, 12]);
+    }
+
+    #[test]
+    fn pop_through_guard() {
+        let mut dll = filled_list();
+
+        assert_eq!(dll.find_mut(&8).unwrap().pop(), 8);
+        assert!(dll.get_mut(10).is_none());
+        assert!(dll.find_mut(&3).is_none());
+        assert_eq!(values(&dll), vec![1, 4, 5, 12]);
+
+        let mut empty = DLList::<i32>::new();
+        assert!(empty.peek_front_mut().is_none());
+    }
+}
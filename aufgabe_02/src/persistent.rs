@@ -0,0 +1,363 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+
+/*Persistente (unveränderliche) sortierte Liste
+
+Anders als bei DLList wird die Liste nie verändert. push und remove geben eine neue Version
+zurück, die alte Version bleibt unverändert gültig.
+
+Structural Sharing:
+    Die Knoten sind einfach verkettet und werden über Rc geteilt. Bei einem push werden nur die
+    Knoten vor der Einfügestelle kopiert, der Rest der Liste (der "Schwanz") wird von der alten
+    und der neuen Version gemeinsam benutzt. Dadurch kann man sehr viele Versionen günstig behalten.
+
+    alt: 1 -> 3 -> 5 -> 7
+                    ^
+    neu: 1'-> 3'-> 4 |      (push(4): 1 und 3 kopiert, 5 -> 7 geteilt)
+
+Ein prev-Zeiger ist hier nicht möglich, da ein geteilter Knoten mehrere Vorgänger haben kann.
+*/
+type PersistentLink<T> = Option<Rc<PersistentNode<T>>>;
+
+struct PersistentNode<T> {
+    item: T,
+    next: PersistentLink<T>,
+}
+
+pub struct PersistentList<T> {
+    head: PersistentLink<T>,
+    len: usize,
+}
+
+impl<T> PersistentList<T> {
+    //Erstellen einer leeren Liste
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    //Kleinstes Element (Kopf der Liste)
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.item)
+    }
+
+    //Liste ohne das erste Element, teilt sich alle Knoten mit self (O(1))
+    pub fn pop_front(&self) -> Self {
+        match &self.head {
+            None => Self::new(),
+            Some(node) => Self {
+                head: node.next.clone(),
+                len: self.len - 1,
+            },
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    //true, wenn beide Versionen denselben Kopfknoten benutzen, also identisch sind.
+    //Ist deutlich schneller als == , da nichts verglichen werden muss.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (None, None) => true,
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+
+    //Anzahl der Knoten, die am Ende der beiden Versionen geteilt werden
+    pub fn shared_len(&self, other: &Self) -> usize {
+        //Beide Listen auf gleiche Länge bringen, danach parallel laufen bis die Zeiger gleich sind
+        let mut a = self.head.as_ref();
+        let mut b = other.head.as_ref();
+        let mut len_a = self.len;
+        let mut len_b = other.len;
+
+        while len_a > len_b {
+            a = a.and_then(|node| node.next.as_ref());
+            len_a -= 1;
+        }
+        while len_b > len_a {
+            b = b.and_then(|node| node.next.as_ref());
+            len_b -= 1;
+        }
+
+        while let (Some(node_a), Some(node_b)) = (a, b) {
+            if Rc::ptr_eq(node_a, node_b) {
+                return len_a;
+            }
+            a = node_a.next.as_ref();
+            b = node_b.next.as_ref();
+            len_a -= 1;
+        }
+
+        0
+    }
+}
+
+impl<T: Ord + Clone> PersistentList<T> {
+    //Neue Version mit eingefügtem Wert. Die Knoten vor der Einfügestelle werden kopiert.
+    pub fn push(&self, wert: T) -> Self {
+        let mut prefix: Vec<T> = Vec::new();
+        let mut node = self.head.as_ref();
+
+        //Alle kleineren Elemente merken, gleiche Werte werden (wie bei DLList) davor eingefügt
+        while let Some(n) = node {
+            if n.item >= wert {
+                break;
+            }
+            prefix.push(n.item.clone());
+            node = n.next.as_ref();
+        }
+
+        let tail = Rc::new(PersistentNode {
+            item: wert,
+            next: node.cloned(),
+        });
+
+        Self {
+            head: Some(Self::build_prefix(prefix, tail)),
+            len: self.len + 1,
+        }
+    }
+
+    //Neue Version ohne ein Vorkommen von wert.
+    //Ist der Wert nicht enthalten, wird eine Version zurückgegeben, die sich alles mit self teilt.
+    pub fn remove<Q: Ord + ?Sized>(&self, wert: &Q) -> Self
+    where
+        T: Borrow<Q>,
+    {
+        let mut prefix: Vec<T> = Vec::new();
+        let mut node = self.head.as_ref();
+
+        while let Some(n) = node {
+            let ordering = n.item.borrow().cmp(wert);
+            if ordering == Ordering::Equal {
+                //Gefunden -> Präfix vor den Nachfolger des gelöschten Knoten hängen
+                let head = match n.next.clone() {
+                    Some(rest) => Some(Self::build_prefix(prefix, rest)),
+                    None => Self::build_list(prefix),
+                };
+                return Self {
+                    head,
+                    len: self.len - 1,
+                };
+            }
+            if ordering == Ordering::Greater {
+                break;
+            }
+            prefix.push(n.item.clone());
+            node = n.next.as_ref();
+        }
+
+        self.clone()
+    }
+
+    pub fn contains<Q: Ord + ?Sized>(&self, element: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        for item in self.iter() {
+            match item.borrow().cmp(element) {
+                Ordering::Equal => return true,
+                //Liste ist sortiert, ab hier kann der Wert nicht mehr kommen
+                Ordering::Greater => return false,
+                Ordering::Less => {}
+            }
+        }
+
+        false
+    }
+
+    //Kopierte Elemente von hinten nach vorne vor den geteilten Rest hängen
+    fn build_prefix(prefix: Vec<T>, rest: Rc<PersistentNode<T>>) -> Rc<PersistentNode<T>> {
+        let mut head = rest;
+        for item in prefix.into_iter().rev() {
+            head = Rc::new(PersistentNode {
+                item,
+                next: Some(head),
+            });
+        }
+        head
+    }
+
+    fn build_list(items: Vec<T>) -> PersistentLink<T> {
+        let mut head = None;
+        for item in items.into_iter().rev() {
+            head = Some(Rc::new(PersistentNode { item, next: head }));
+        }
+        head
+    }
+}
+
+impl<T: Clone> PersistentList<T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        self.iter().cloned().collect()
+    }
+}
+
+//Eine Kopie teilt sich alle Knoten, es wird nur der Ref. Count vom Kopf erhöht
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PersistentList<T> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/*Drop
+Ohne eigenes Drop würde eine lange Liste rekursiv freigegeben (Stack Overflow).
+Es wird nur so lange abgebaut, wie der Knoten nur noch dieser Version gehört.
+Sobald ein Knoten noch von einer anderen Version benutzt wird, bleibt der Rest stehen.
+*/
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        let mut node = self.head.take();
+        while let Some(rc) = node {
+            match Rc::try_unwrap(rc) {
+                Ok(mut inner) => node = inner.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a PersistentNode<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.item
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_test_persistent() {
+        let mut list = PersistentList::<i32>::new();
+
+        for ele in [8, 6, 17, 35, 888, 1, 0] {
+            list = list.push(ele);
+        }
+
+        assert_eq!(list.to_vec(), vec![0, 1, 6, 8, 17, 35, 888]);
+        assert_eq!(list.len(), 7);
+    }
+
+    #[test]
+    fn old_versions_stay_unchanged() {
+        let v1 = PersistentList::new().push(1).push(3).push(5);
+        let v2 = v1.push(4);
+        let v3 = v2.remove(&1);
+
+        assert_eq!(v1.to_vec(), vec![1, 3, 5]);
+        assert_eq!(v2.to_vec(), vec![1, 3, 4, 5]);
+        assert_eq!(v3.to_vec(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn structural_sharing() {
+        let v1 = PersistentList::new().push(1).push(3).push(5).push(7);
+        let v2 = v1.push(4);
+
+        //5 -> 7 wird von beiden Versionen benutzt
+        assert_eq!(v1.shared_len(&v2), 2);
+
+        //pop_front teilt sich alles
+        let v3 = v1.pop_front();
+        assert_eq!(v1.shared_len(&v3), 3);
+
+        //Entfernen eines nicht vorhandenen Wertes ändert nichts
+        let v4 = v1.remove(&100);
+        assert!(v1.ptr_eq(&v4));
+    }
+
+    #[test]
+    fn compare_versions() {
+        let a = PersistentList::new().push(2).push(1);
+        let b = PersistentList::new().push(1).push(2);
+
+        assert_eq!(a, b);
+        assert!(!a.ptr_eq(&b));
+        assert_ne!(a, b.push(3));
+    }
+
+    #[test]
+    fn remove_and_contains_persistent() {
+        let list = PersistentList::new().push(5).push(5).push(2).push(9);
+
+        assert!(list.contains(&5));
+        assert!(!list.contains(&6));
+
+        let removed = list.remove(&5);
+        assert_eq!(removed.to_vec(), vec![2, 5, 9]);
+
+        let removed = removed.remove(&9);
+        assert_eq!(removed.to_vec(), vec![2, 5]);
+        assert_eq!(removed.len(), 2);
+    }
+
+    #[test]
+    fn empty_list_function_test_persistent() {
+        let list = PersistentList::<i32>::new();
+
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert!(list.pop_front().is_empty());
+        assert_eq!(list.remove(&1).to_vec(), vec![]);
+    }
+
+    #[test]
+    fn long_list_drop_persistent() {
+        //Darf beim Freigeben keinen Stack Overflow erzeugen
+        let mut list = PersistentList::new();
+        for ele in (0..100_000).rev() {
+            list = list.push(ele);
+        }
+        let copy = list.pop_front();
+        drop(list);
+        assert_eq!(copy.len(), 99_999);
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::mem::MaybeUninit;
+
+use crate::{DLList, Link, Node, take_item};
+
+/*Node Pool (Freiliste)
+
+Jedes push legt einen neuen Rc<RefCell<Node>> auf dem Heap an, jedes pop_* gibt ihn wieder frei.
+Bei vielen push/pop im Wechsel wird also ständig Speicher angefordert und freigegeben.
+
+Mit dem Pool wird der Speicher eines ausgehängten Knotens nicht freigegeben, sondern in einer
+Freiliste aufgehoben und beim nächsten push wiederverwendet:
+
+    pop:  Wert aus dem Knoten herauslesen, der leere Speicher (MaybeUninit) kommt in die Freiliste
+    push: Speicher aus der Freiliste holen und den neuen Knoten hineinschreiben
+
+Ein Knoten wird nur aufgehoben, wenn es keine andere Referenz (auch kein Weak) mehr auf ihn gibt,
+sonst könnte ein alter Weak-Zeiger später auf einen fremden Knoten zeigen.
+
+Der Pool ist nur aktiv, wenn die Liste mit with_capacity angelegt oder reserve aufgerufen wurde.
+*/
+type PoolSlot<T> = Rc<MaybeUninit<RefCell<Node<T>>>>;
+
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct PoolStats {
+    //Knoten, die aus der Freiliste genommen wurden (gesparte Allokationen)
+    pub hits: usize,
+    //Knoten, die neu angelegt werden mussten, weil die Freiliste leer war
+    pub misses: usize,
+    //Knoten, die nach pop/remove in die Freiliste gelegt wurden
+    pub recycled: usize,
+}
+
+pub(crate) struct NodePool<T> {
+    free: Vec<PoolSlot<T>>,
+    enabled: bool,
+    stats: PoolStats,
+}
+
+impl<T> NodePool<T> {
+    pub(crate) fn disabled() -> Self {
+        Self {
+            free: Vec::new(),
+            enabled: false,
+            stats: PoolStats::default(),
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.enabled = true;
+        self.free.reserve(additional);
+        for _ in 0..additional {
+            self.free.push(Rc::new_uninit());
+        }
+    }
+
+    pub(crate) fn alloc(&mut self, wert: T) -> Link<T> {
+        let node = RefCell::new(Node::new(wert));
+
+        if !self.enabled {
+            return Rc::new(node);
+        }
+
+        match self.free.pop() {
+            Some(mut slot) => {
+                self.stats.hits += 1;
+                //Slots in der Freiliste haben keine weiteren Referenzen, get_mut klappt immer
+                Rc::get_mut(&mut slot).unwrap().write(node);
+                // SAFETY: der Inhalt wurde gerade geschrieben
+                unsafe { slot.assume_init() }
+            }
+            None => {
+                self.stats.misses += 1;
+                Rc::new(node)
+            }
+        }
+    }
+
+    //Wert aus einem ausgehängten Knoten holen und den Speicher wenn möglich aufheben
+    pub(crate) fn free(&mut self, node: Link<T>) -> T {
+        if !self.enabled || Rc::strong_count(&node) != 1 || Rc::weak_count(&node) != 0 {
+            return take_item(node);
+        }
+
+        let ptr = Rc::into_raw(node);
+        // SAFETY: es gibt keine andere Referenz auf den Knoten. Der Inhalt wird genau einmal
+        // herausgelesen, danach gilt der Speicher als uninitialisiert. MaybeUninit<X> hat die
+        // gleiche Größe und Ausrichtung wie X, daher darf der Zeiger so zurückgewandelt werden.
+        let (cell, slot) = unsafe {
+            let cell = core::ptr::read(ptr);
+            let slot: PoolSlot<T> = Rc::from_raw(ptr.cast());
+            (cell, slot)
+        };
+
+        self.free.push(slot);
+        self.stats.recycled += 1;
+        cell.into_inner().item
+    }
+}
+
+impl<T: Ord> DLList<T> {
+    //Liste mit aktivem Pool, in dem schon capacity Knoten bereitliegen
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut list = Self::new();
+        list.reserve(capacity);
+        list
+    }
+}
+
+impl<T> DLList<T> {
+    //Weitere Knoten im Pool anlegen, aktiviert den Pool
+    pub fn reserve(&mut self, additional: usize) {
+        self.pool.reserve(additional);
+    }
+
+    //Alle freien Knoten im Pool freigeben (der Pool bleibt aktiv)
+    pub fn shrink_to_fit(&mut self) {
+        self.pool.free.clear();
+        self.pool.free.shrink_to_fit();
+    }
+
+    //Anzahl der freien Knoten im Pool
+    pub fn pool_len(&self) -> usize {
+        self.pool.free.len()
+    }
+
+    pub fn pool_stats(&self) -> PoolStats {
+        self.pool.stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool_reuses_nodes() {
+        let mut dll = DLList::<i32>::with_capacity(2);
+        assert_eq!(dll.pool_len(), 2);
+
+        dll.push(5);
+        dll.push(3);
+        dll.push(4);
+        assert_eq!(dll.pool_len(), 0);
+        assert_eq!(
+            dll.pool_stats(),
+            PoolStats {
+                hits: 2,
+                misses: 1,
+                recycled: 0
+            }
+        );
+
+        assert_eq!(dll.pop_front(), Some(3));
+        assert_eq!(dll.remove(&5), Some(5));
+        assert_eq!(dll.pool_len(), 2);
+
+        dll.push(1);
+        assert_eq!(dll.pool_stats().hits, 3);
+        assert_eq!(dll.pool_stats().recycled, 2);
+        assert_eq!(dll.to_vec(), vec![1, 4]);
+    }
+
+    #[test]
+    fn list_without_pool() {
+        let mut dll = DLList::<i32>::new();
+
+        dll.push(1);
+        dll.pop_back();
+        assert_eq!(dll.pool_len(), 0);
+        assert_eq!(dll.pool_stats(), PoolStats::default());
+
+        //reserve aktiviert den Pool
+        dll.reserve(1);
+        dll.push(2);
+        dll.pop_back();
+        assert_eq!(dll.pool_stats().hits, 1);
+        assert_eq!(dll.pool_len(), 1);
+    }
+
+    #[test]
+    fn shrink_to_fit_frees_pool() {
+        let mut dll = DLList::<String>::with_capacity(10);
+
+        dll.push("b".to_string());
+        dll.push("a".to_string());
+        assert_eq!(dll.pop_back(), Some("b".to_string()));
+        assert_eq!(dll.pool_len(), 9);
+
+        dll.shrink_to_fit();
+        assert_eq!(dll.pool_len(), 0);
+        dll.push("c".to_string());
+        assert_eq!(dll.to_vec(), vec!["a".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn node_with_weak_reference_is_not_recycled() {
+        let mut dll = DLList::<i32>::with_capacity(0);
+        dll.push(1);
+
+        //Ein fremder Weak-Zeiger darf nach dem Wiederverwenden nicht auf den neuen Knoten zeigen
+        let weak = Rc::downgrade(dll.head.as_ref().unwrap());
+        assert_eq!(dll.pop_front(), Some(1));
+        assert_eq!(dll.pool_len(), 0);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn steady_state_push_pop() {
+        let mut dll = DLList::<u32>::with_capacity(16);
+
+        for ele in 0..16 {
+            dll.push(ele);
+        }
+        for ele in 16..1000 {
+            dll.pop_front();
+            dll.push(ele);
+        }
+
+        //Nach dem Auffüllen kommt jeder Knoten aus dem Pool
+        assert_eq!(dll.pool_stats().misses, 0);
+        assert_eq!(dll.pool_stats().hits, 1000);
+        assert_eq!(dll.to_vec(), (984..1000).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,380 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/*Sortierte Liste mit rohen Zeigern (NonNull)
+
+Dritte Variante neben DLList (Rc + Weak) und DLListDrop (Rc in beide Richtungen):
+Die Knoten werden mit Box angelegt und nur über NonNull-Zeiger verkettet.
+    - kein Ref. Count (Rc)
+    - keine Laufzeit-Prüfung beim Ausleihen (RefCell)
+    - iter() kann echte &T Referenzen zurückgeben
+
+Die Liste besitzt alle Knoten selbst, jeder Knoten wird genau einmal mit Box::into_raw angelegt
+und genau einmal mit Box::from_raw wieder freigegeben (pop_*, remove oder Drop).
+Alle unsafe-Blöcke stehen hinter einer sicheren öffentlichen Schnittstelle.
+
+Die Tests sind so klein gehalten, dass sie auch unter Miri laufen:
+    cargo +nightly miri test raw
+*/
+type RawLink<T> = Option<NonNull<RawNode<T>>>;
+
+struct RawNode<T> {
+    item: T,
+    next: RawLink<T>,
+    prev: RawLink<T>,
+}
+
+pub struct RawDLList<T> {
+    head: RawLink<T>,
+    tail: RawLink<T>,
+    len: usize,
+    //Sagt dem Compiler, dass die Liste Knoten (und damit T) besitzt. Wirkt nur auf den Drop-Check
+    //und die Varianz (kovariant in T). Send/Sync ändert sich dadurch nicht, wegen NonNull ist
+    //RawDLList weder Send noch Sync.
+    marker: PhantomData<Box<RawNode<T>>>,
+}
+
+impl<T> RawDLList<T> {
+    pub fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        // SAFETY: head zeigt immer auf einen gültigen Knoten der Liste, der so lange lebt wie &self
+        self.head.map(|node| unsafe { &(*node.as_ptr()).item })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        // SAFETY: wie bei front
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).item })
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.head?;
+        // SAFETY: node gehört zur Liste und wird danach nicht mehr benutzt
+        Some(unsafe { self.unlink(node) })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let node = self.tail?;
+        // SAFETY: wie bei pop_front
+        Some(unsafe { self.unlink(node) })
+    }
+
+    pub fn iter(&self) -> RawIter<'_, T> {
+        RawIter {
+            next: self.head,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    //Knoten aushängen und freigeben, gibt den Wert zurück.
+    //SAFETY: node muss ein Knoten dieser Liste sein
+    unsafe fn unlink(&mut self, node: NonNull<RawNode<T>>) -> T {
+        // SAFETY: laut Vorbedingung ist node ein gültiger Knoten, der mit Box::into_raw angelegt wurde.
+        // Nach dem Aushängen zeigt kein Zeiger der Liste mehr auf ihn.
+        unsafe {
+            let boxed = Box::from_raw(node.as_ptr());
+
+            match boxed.prev {
+                Some(prev) => (*prev.as_ptr()).next = boxed.next,
+                None => self.head = boxed.next,
+            }
+            match boxed.next {
+                Some(next) => (*next.as_ptr()).prev = boxed.prev,
+                None => self.tail = boxed.prev,
+            }
+
+            self.len -= 1;
+            boxed.item
+        }
+    }
+}
+
+impl<T: Ord> RawDLList<T> {
+    pub fn push(&mut self, wert: T) {
+        //Ersten Knoten suchen, der >= wert ist (gleiche Werte wie bei DLList davor einfügen)
+        let mut node_after = self.head;
+        while let Some(node) = node_after {
+            // SAFETY: alle Knoten der Kette sind gültig
+            let node_ref = unsafe { &*node.as_ptr() };
+            if node_ref.item >= wert {
+                break;
+            }
+            node_after = node_ref.next;
+        }
+
+        let node_before = match node_after {
+            // SAFETY: node ist ein gültiger Knoten
+            Some(node) => unsafe { (*node.as_ptr()).prev },
+            None => self.tail,
+        };
+
+        let new_node = Box::new(RawNode {
+            item: wert,
+            next: node_after,
+            prev: node_before,
+        });
+        // SAFETY: Box::into_raw gibt nie einen Null-Zeiger zurück
+        let new_node = unsafe { NonNull::new_unchecked(Box::into_raw(new_node)) };
+
+        // SAFETY: node_before und node_after sind gültige Knoten (oder None)
+        unsafe {
+            match node_before {
+                Some(node) => (*node.as_ptr()).next = Some(new_node),
+                None => self.head = Some(new_node),
+            }
+            match node_after {
+                Some(node) => (*node.as_ptr()).prev = Some(new_node),
+                None => self.tail = Some(new_node),
+            }
+        }
+
+        self.len += 1;
+    }
+
+    pub fn contains<Q: Ord + ?Sized>(&self, element: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.find_node(element).is_some()
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        let node = self.find_node(element)?;
+        // SAFETY: find_node gibt nur Knoten dieser Liste zurück
+        Some(unsafe { self.unlink(node) })
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_vec(&mut self) -> Vec<T> {
+        let mut out_vec = Vec::with_capacity(self.len);
+
+        while let Some(val) = self.pop_front() {
+            out_vec.push(val);
+        }
+
+        out_vec
+    }
+
+    fn find_node<Q: Ord + ?Sized>(&self, element: &Q) -> RawLink<T>
+    where
+        T: Borrow<Q>,
+    {
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            // SAFETY: alle Knoten der Kette sind gültig
+            let node_ref = unsafe { &*node.as_ptr() };
+            match node_ref.item.borrow().cmp(element) {
+                Ordering::Equal => return Some(node),
+                //Liste ist sortiert, danach kann der Wert nicht mehr kommen
+                Ordering::Greater => return None,
+                Ordering::Less => {}
+            }
+            current = node_ref.next;
+        }
+
+        None
+    }
+}
+
+impl<T> Drop for RawDLList<T> {
+    fn drop(&mut self) {
+        //Iterativ freigeben, damit auch sehr lange Listen keinen Stack Overflow erzeugen
+        self.clear();
+    }
+}
+
+impl<T> Default for RawDLList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for RawDLList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = RawDLList::new();
+        for wert in iter {
+            list.push(wert);
+        }
+        list
+    }
+}
+
+pub struct RawIter<'a, T> {
+    next: RawLink<T>,
+    len: usize,
+    marker: PhantomData<&'a RawNode<T>>,
+}
+
+impl<'a, T> Iterator for RawIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.next?;
+        // SAFETY: die Liste ist für 'a ausgeliehen, kein Knoten kann in der Zeit freigegeben werden
+        let node_ref = unsafe { &*node.as_ptr() };
+        self.next = node_ref.next;
+        self.len -= 1;
+        Some(&node_ref.item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+pub struct RawIntoIter<T> {
+    list: RawDLList<T>,
+}
+
+impl<T> Iterator for RawIntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for RawIntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for RawDLList<T> {
+    type Item = T;
+    type IntoIter = RawIntoIter<T>;
+
+    fn into_iter(self) -> RawIntoIter<T> {
+        RawIntoIter { list: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn sort_test_raw() {
+        let mut dll: RawDLList<i32> = [8, 6, 17, 35, 888, 1, 0].into_iter().collect();
+
+        assert_eq!(dll.len(), 7);
+        assert_eq!(dll.front(), Some(&0));
+        assert_eq!(dll.back(), Some(&888));
+        assert_eq!(dll.to_vec(), vec![0, 1, 6, 8, 17, 35, 888]);
+        assert!(dll.is_empty());
+    }
+
+    #[test]
+    fn empty_list_function_test_raw() {
+        let mut dll = RawDLList::<i32>::new();
+
+        assert_eq!(dll.to_vec(), vec![]);
+        assert_eq!(dll.pop_back(), None);
+        assert_eq!(dll.pop_front(), None);
+        assert_eq!(dll.remove(&1), None);
+        assert_eq!(dll.iter().next(), None);
+    }
+
+    #[test]
+    fn pop_front_pop_back_raw() {
+        let mut dll: RawDLList<i32> = [8, 6, 17, 35, 888, 1, 0].into_iter().collect();
+
+        assert_eq!(dll.pop_front(), Some(0));
+        assert_eq!(dll.pop_back(), Some(888));
+        assert_eq!(dll.pop_back(), Some(35));
+        assert_eq!(dll.pop_front(), Some(1));
+        assert_eq!(dll.iter().copied().collect::<Vec<_>>(), vec![6, 8, 17]);
+    }
+
+    #[test]
+    fn contains_and_remove_raw() {
+        let mut dll: RawDLList<i32> = [5, 3, 5, 9, 1].into_iter().collect();
+
+        assert!(dll.contains(&9));
+        assert!(!dll.contains(&4));
+
+        //Anfang, Mitte und Ende
+        assert_eq!(dll.remove(&1), Some(1));
+        assert_eq!(dll.remove(&5), Some(5));
+        assert_eq!(dll.remove(&9), Some(9));
+        assert_eq!(dll.remove(&9), None);
+
+        assert_eq!(dll.back(), Some(&5));
+        assert_eq!(dll.into_iter().rev().collect::<Vec<_>>(), vec![5, 3]);
+    }
+
+    #[test]
+    fn drop_frees_all_items_raw() {
+        //Jeder Wert hält eine Rc, nach dem Drop der Liste darf keine mehr übrig sein
+        let counter = Rc::new(());
+        let mut dll = RawDLList::new();
+        for ele in [3, 1, 2] {
+            dll.push((ele, counter.clone()));
+        }
+        assert_eq!(Rc::strong_count(&counter), 4);
+
+        let (first, _) = dll.pop_front().unwrap();
+        assert_eq!(first, 1);
+        assert_eq!(Rc::strong_count(&counter), 3);
+
+        drop(dll);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn heap_items_raw() {
+        let mut dll = RawDLList::new();
+        for word in ["kiwi", "apfel", "birne"] {
+            dll.push(word.to_string());
+        }
+
+        assert_eq!(
+            dll.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+            vec!["apfel", "birne", "kiwi"]
+        );
+        assert_eq!(dll.remove(&"birne".to_string()), Some("birne".to_string()));
+    }
+
+    #[test]
+    fn stress_test_raw() {
+        //Unter Miri wird nur eine kleine Anzahl getestet, da Miri sehr langsam ist
+        let count = if cfg!(miri) { 50 } else { 1000 };
+        let mut dll = RawDLList::new();
+
+        for ele in (0..count).rev() {
+            dll.push(ele);
+        }
+
+        let expected: Vec<_> = (0..count).collect();
+        assert_eq!(dll.to_vec(), expected);
+    }
+}
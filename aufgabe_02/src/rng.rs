@@ -0,0 +1,13 @@
+//Einfacher Pseudo-Zufallsgenerator (LCG), damit keine externen Crates gebraucht werden.
+//Wird nur von den Benchmarks (std) und den Zufallstests benutzt, gleicher Startwert -> gleiche Folge.
+pub(crate) struct Lcg(pub(crate) u64);
+
+impl Lcg {
+    pub(crate) fn next(&mut self) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) as u32
+    }
+}
@@ -0,0 +1,281 @@
+use core::cmp::Ordering;
+use core::iter::Peekable;
+
+use crate::{DLList, Iter};
+
+/*Mengenoperationen
+
+Da beide Listen sortiert sind, können Vereinigung, Schnitt, Differenz und symmetrische Differenz
+wie beim Merge-Sort in einem Durchlauf (linear) berechnet werden.
+
+Gleiche Werte werden als Multimenge behandelt (wie oft kommt ein Wert vor):
+    union:                max(a, b)
+    intersection:         min(a, b)
+    difference:           a - b       (nicht kleiner als 0)
+    symmetric_difference: |a - b|
+
+Es gibt zwei Varianten:
+    union(&a, &b)       -> lazy Iterator, die Listen bleiben unverändert (T: Clone)
+    a.into_union(b)     -> verbraucht beide Listen, die Knoten werden ohne neue Allokation
+                           in die Ergebnisliste umgehängt
+*/
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+impl SetOp {
+    //Wird ein Wert behalten, der nur in a (bzw. nur in b) oder in beiden vorkommt?
+    fn keep_only_a(self) -> bool {
+        matches!(
+            self,
+            SetOp::Union | SetOp::Difference | SetOp::SymmetricDifference
+        )
+    }
+
+    fn keep_only_b(self) -> bool {
+        matches!(self, SetOp::Union | SetOp::SymmetricDifference)
+    }
+
+    fn keep_both(self) -> bool {
+        matches!(self, SetOp::Union | SetOp::Intersection)
+    }
+}
+
+//Lazy Iterator über das Ergebnis einer Mengenoperation zweier sortierter Iteratoren
+pub struct SetOperation<I: Iterator> {
+    a: Peekable<I>,
+    b: Peekable<I>,
+    op: SetOp,
+}
+
+impl<I: Iterator> SetOperation<I>
+where
+    I::Item: Ord,
+{
+    //a und b müssen aufsteigend sortiert sein
+    pub fn new(a: I, b: I, op: SetOp) -> Self {
+        Self {
+            a: a.peekable(),
+            b: b.peekable(),
+            op,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for SetOperation<I>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        loop {
+            let order = match (self.a.peek(), self.b.peek()) {
+                (None, None) => return None,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(x), Some(y)) => x.cmp(y),
+            };
+
+            match order {
+                Ordering::Less => {
+                    let item = self.a.next();
+                    if self.op.keep_only_a() {
+                        return item;
+                    }
+                }
+                Ordering::Greater => {
+                    let item = self.b.next();
+                    if self.op.keep_only_b() {
+                        return item;
+                    }
+                }
+                Ordering::Equal => {
+                    let item = self.a.next();
+                    self.b.next();
+                    if self.op.keep_both() {
+                        return item;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone> DLList<T> {
+    pub fn union(&self, other: &DLList<T>) -> SetOperation<Iter<T>> {
+        SetOperation::new(self.iter(), other.iter(), SetOp::Union)
+    }
+
+    pub fn intersection(&self, other: &DLList<T>) -> SetOperation<Iter<T>> {
+        SetOperation::new(self.iter(), other.iter(), SetOp::Intersection)
+    }
+
+    pub fn difference(&self, other: &DLList<T>) -> SetOperation<Iter<T>> {
+        SetOperation::new(self.iter(), other.iter(), SetOp::Difference)
+    }
+
+    pub fn symmetric_difference(&self, other: &DLList<T>) -> SetOperation<Iter<T>> {
+        SetOperation::new(self.iter(), other.iter(), SetOp::SymmetricDifference)
+    }
+}
+
+impl<T: Ord> DLList<T> {
+    pub fn into_union(self, other: DLList<T>) -> DLList<T> {
+        self.merge_nodes(other, SetOp::Union)
+    }
+
+    pub fn into_intersection(self, other: DLList<T>) -> DLList<T> {
+        self.merge_nodes(other, SetOp::Intersection)
+    }
+
+    pub fn into_difference(self, other: DLList<T>) -> DLList<T> {
+        self.merge_nodes(other, SetOp::Difference)
+    }
+
+    pub fn into_symmetric_difference(self, other: DLList<T>) -> DLList<T> {
+        self.merge_nodes(other, SetOp::SymmetricDifference)
+    }
+
+    //Gleiche Nachbarn zusammenfassen, danach kommt jeder Wert nur noch einmal vor
+    pub fn dedup(&mut self) {
+        let mut current = self.head.clone();
+
+        while let Some(curr) = current {
+            let next = curr.borrow().next.clone();
+
+            match next {
+                Some(next_node) if next_node.borrow().item == curr.borrow().item => {
+                    //Nachbar aushängen und mit dem gleichen Knoten weitermachen
+                    self.unlink_node(&next_node);
+                    current = Some(curr);
+                }
+                next => current = next,
+            }
+        }
+    }
+
+    //Gleicher Ablauf wie SetOperation::next, aber es werden die Knoten selbst umgehängt
+    fn merge_nodes(mut self, mut other: DLList<T>, op: SetOp) -> DLList<T> {
+        let mut result = DLList::new();
+
+        loop {
+            let order = match (&self.head, &other.head) {
+                (None, None) => break,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(x), Some(y)) => x.borrow().item.cmp(&y.borrow().item),
+            };
+
+            match order {
+                Ordering::Less => {
+                    let node = self.pop_front_node().unwrap();
+                    if op.keep_only_a() {
+                        result.push_back_node(node);
+                    }
+                }
+                Ordering::Greater => {
+                    let node = other.pop_front_node().unwrap();
+                    if op.keep_only_b() {
+                        result.push_back_node(node);
+                    }
+                }
+                Ordering::Equal => {
+                    let node = self.pop_front_node().unwrap();
+                    other.pop_front_node();
+                    if op.keep_both() {
+                        result.push_back_node(node);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(values: &[i32]) -> DLList<i32> {
+        values.iter().copied().collect()
+    }
+
+    #[test]
+    fn lazy_set_operations() {
+        let a = list(&[1, 2, 2, 2, 4, 7]);
+        let b = list(&[2, 3, 4, 4, 7, 9]);
+
+        assert_eq!(
+            a.union(&b).collect::<Vec<_>>(),
+            vec![1, 2, 2, 2, 3, 4, 4, 7, 9]
+        );
+        assert_eq!(a.intersection(&b).collect::<Vec<_>>(), vec![2, 4, 7]);
+        assert_eq!(a.difference(&b).collect::<Vec<_>>(), vec![1, 2, 2]);
+        assert_eq!(b.difference(&a).collect::<Vec<_>>(), vec![3, 4, 9]);
+        assert_eq!(
+            a.symmetric_difference(&b).collect::<Vec<_>>(),
+            vec![1, 2, 2, 3, 4, 9]
+        );
+
+        //Die Listen bleiben unverändert
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![1, 2, 2, 2, 4, 7]);
+    }
+
+    #[test]
+    fn consuming_set_operations() {
+        let a = [1, 2, 2, 2, 4, 7];
+        let b = [2, 3, 4, 4, 7, 9];
+
+        assert_eq!(
+            list(&a).into_union(list(&b)).to_vec(),
+            vec![1, 2, 2, 2, 3, 4, 4, 7, 9]
+        );
+        assert_eq!(list(&a).into_intersection(list(&b)).to_vec(), vec![2, 4, 7]);
+        assert_eq!(list(&a).into_difference(list(&b)).to_vec(), vec![1, 2, 2]);
+        assert_eq!(
+            list(&a).into_symmetric_difference(list(&b)).to_vec(),
+            vec![1, 2, 2, 3, 4, 9]
+        );
+    }
+
+    #[test]
+    fn consuming_keeps_links_valid() {
+        let mut result = list(&[5, 1, 3]).into_union(list(&[2, 3, 6]));
+
+        //prev-Zeiger müssen stimmen, sonst geht pop_back schief
+        assert_eq!(result.pop_back(), Some(6));
+        result.push(4);
+        assert_eq!(result.pop_back(), Some(5));
+        assert_eq!(result.to_vec(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn set_operations_with_empty_list() {
+        let a = list(&[1, 2]);
+        let empty = list(&[]);
+
+        assert_eq!(a.union(&empty).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(empty.union(&a).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(a.intersection(&empty).count(), 0);
+        assert_eq!(empty.difference(&a).count(), 0);
+        assert!(list(&[]).into_symmetric_difference(list(&[])).is_empty());
+    }
+
+    #[test]
+    fn dedup_test() {
+        let mut dll = list(&[3, 1, 1, 2, 3, 3, 3, 1]);
+        dll.dedup();
+        assert_eq!(dll.pop_back(), Some(3));
+        assert_eq!(dll.to_vec(), vec![1, 2]);
+
+        let mut empty = list(&[]);
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+}
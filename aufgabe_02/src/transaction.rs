@@ -0,0 +1,276 @@
+use alloc::vec::Vec;
+use core::borrow;
+
+use crate::{DLList, Link};
+
+/*Transaktionen
+
+Mehrere push/pop/remove Aufrufe werden zusammen ausgeführt: entweder alle oder keiner.
+
+    list.transaction(|tx| {
+        tx.push(4);
+        tx.pop_front();
+        Ok::<_, ()>(())
+    })
+
+Jede Änderung wird in einem Journal gespeichert. Gibt die Closure Err zurück oder gibt es eine
+Panik, dann wird das Journal rückwärts abgearbeitet und jede Änderung rückgängig gemacht.
+
+Ausgehängte Knoten werden bis zum Ende der Transaktion im Journal gehalten und beim Rollback
+wieder genau an die alte Stelle gehängt (es wird kein neuer Knoten erzeugt). Deshalb gibt pop/remove
+eine Kopie des Wertes zurück (T: Clone).
+*/
+enum JournalEntry<T> {
+    //Knoten wurde neu eingefügt -> beim Rollback wieder aushängen
+    Inserted(Link<T>),
+    //Knoten wurde ausgehängt -> beim Rollback zwischen prev und next wieder einhängen
+    Removed {
+        node: Link<T>,
+        prev: Option<Link<T>>,
+        next: Option<Link<T>>,
+    },
+}
+
+pub struct Transaction<'a, T: Ord> {
+    list: &'a mut DLList<T>,
+    journal: Vec<JournalEntry<T>>,
+    committed: bool,
+}
+
+impl<T: Ord + Clone> DLList<T> {
+    pub fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut Transaction<'_, T>) -> Result<R, E>,
+    {
+        let mut tx = Transaction {
+            list: self,
+            journal: Vec::new(),
+            committed: false,
+        };
+
+        //Bei einer Panik in f wird tx beim Unwinding gedroppt -> Drop macht den Rollback
+        let result = f(&mut tx);
+
+        if result.is_ok() {
+            tx.committed = true;
+        }
+
+        result
+    }
+}
+
+impl<T: Ord + Clone> Transaction<'_, T> {
+    pub fn push(&mut self, wert: T) {
+        let new_node = self.list.push_node(wert);
+        self.journal.push(JournalEntry::Inserted(new_node));
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.list.head.clone()?;
+        Some(self.detach(node))
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let node = self.list.tail.clone()?;
+        Some(self.detach(node))
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let node = self.list.find_node(element)?;
+        Some(self.detach(node))
+    }
+
+    pub fn contains<Q: Ord + ?Sized>(&self, element: &Q) -> bool
+    where
+        T: borrow::Borrow<Q>,
+    {
+        self.list.find_node(element).is_some()
+    }
+
+    //Anzahl der bisher in der Transaktion ausgeführten Änderungen
+    pub fn len(&self) -> usize {
+        self.journal.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.journal.is_empty()
+    }
+
+    fn detach(&mut self, node: Link<T>) -> T {
+        let (prev, next) = self.list.unlink_node(&node);
+        let item = node.borrow().item.clone();
+
+        self.journal
+            .push(JournalEntry::Removed { node, prev, next });
+        item
+    }
+}
+
+impl<T: Ord> Transaction<'_, T> {
+    //Journal rückwärts abarbeiten, danach ist die Liste wieder im Zustand vor der Transaktion
+    fn rollback(&mut self) {
+        while let Some(entry) = self.journal.pop() {
+            match entry {
+                JournalEntry::Inserted(node) => {
+                    self.list.unlink_node(&node);
+                }
+                JournalEntry::Removed { node, prev, next } => {
+                    self.list.link_node(&node, prev, next);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Ord> Drop for Transaction<'_, T> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.rollback();
+        }
+    }
+}
+
+//Alle Knoten der Liste (für Vergleiche in Tests), prüft dabei auch die prev-Zeiger
+#[cfg(test)]
+pub(crate) fn nodes<T>(list: &DLList<T>) -> Vec<Link<T>> {
+    use crate::get_next;
+    use alloc::rc::Rc;
+
+    let mut out: Vec<Link<T>> = Vec::new();
+    let mut current = list.head.clone();
+
+    while let Some(curr) = current {
+        let prev = curr.borrow().prev.as_ref().and_then(|weak| weak.upgrade());
+        match (&prev, out.last()) {
+            (None, None) => {}
+            (Some(p), Some(last)) => assert!(Rc::ptr_eq(p, last)),
+            _ => panic!("prev-Zeiger ist falsch"),
+        }
+        current = get_next(&curr);
+        out.push(curr);
+    }
+
+    match (&list.tail, out.last()) {
+        (None, None) => {}
+        (Some(tail), Some(last)) => assert!(Rc::ptr_eq(tail, last)),
+        _ => panic!("tail ist falsch"),
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{AssertUnwindSafe, catch_unwind};
+    use std::rc::Rc;
+
+    fn filled_list() -> DLList<i32> {
+        let mut dll = DLList::new();
+        for ele in [8, 6, 17, 35, 888, 1, 0] {
+            dll.push(ele);
+        }
+        dll
+    }
+
+    //Prüft, dass die Liste aus genau denselben Knoten in derselben Reihenfolge besteht
+    fn assert_same_nodes(list: &DLList<i32>, before: &[Link<i32>]) {
+        let after = nodes(list);
+        assert_eq!(after.len(), before.len());
+        for (a, b) in after.iter().zip(before) {
+            assert!(Rc::ptr_eq(a, b));
+        }
+    }
+
+    #[test]
+    fn commit_transaction() {
+        let mut dll = filled_list();
+
+        let result = dll.transaction(|tx| {
+            tx.push(5);
+            let front = tx.pop_front();
+            let back = tx.pop_back();
+            let removed = tx.remove(&8);
+            Ok::<_, ()>((front, back, removed))
+        });
+
+        assert_eq!(result, Ok((Some(0), Some(888), Some(8))));
+        assert_eq!(dll.to_vec(), vec![1, 5, 6, 17, 35]);
+    }
+
+    #[test]
+    fn rollback_on_err() {
+        let mut dll = filled_list();
+        let before = nodes(&dll);
+
+        let result: Result<(), &str> = dll.transaction(|tx| {
+            tx.push(5);
+            tx.push(1000);
+            tx.pop_front();
+            tx.pop_back();
+            tx.remove(&17);
+            tx.push(-3);
+            assert!(!tx.contains(&17));
+            Err("abbrechen")
+        });
+
+        assert_eq!(result, Err("abbrechen"));
+        assert_same_nodes(&dll, &before);
+        drop(before);
+        assert_eq!(dll.to_vec(), vec![0, 1, 6, 8, 17, 35, 888]);
+    }
+
+    #[test]
+    fn rollback_on_panic() {
+        let mut dll = filled_list();
+        let before = nodes(&dll);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            dll.transaction(|tx| {
+                tx.pop_back();
+                tx.push(3);
+                tx.remove(&0);
+                if tx.len() == 3 {
+                    panic!("Fehler in der Transaktion");
+                }
+                Ok::<_, ()>(())
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_same_nodes(&dll, &before);
+    }
+
+    #[test]
+    fn rollback_empties_list_again() {
+        let mut dll = DLList::<i32>::new();
+
+        let result: Result<(), ()> = dll.transaction(|tx| {
+            tx.push(1);
+            tx.push(2);
+            assert_eq!(tx.pop_front(), Some(1));
+            Err(())
+        });
+
+        assert!(result.is_err());
+        assert!(dll.head.is_none());
+        assert!(dll.tail.is_none());
+    }
+
+    #[test]
+    fn empty_transaction() {
+        let mut dll = filled_list();
+
+        let result: Result<(), ()> = dll.transaction(|tx| {
+            assert!(tx.is_empty());
+            assert_eq!(tx.remove(&7), None);
+            Ok(())
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(dll.to_vec(), vec![0, 1, 6, 8, 17, 35, 888]);
+    }
+}
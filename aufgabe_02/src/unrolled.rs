@@ -0,0 +1,450 @@
+use alloc::rc::{Rc, Weak};
+use alloc::vec::Vec;
+use core::borrow;
+use core::cell::RefCell;
+use core::cmp::Ordering;
+
+use crate::compare;
+
+/*Unrolled sortierte Liste
+
+Bei DLList enthält jeder Knoten genau ein item. Das kostet pro Element eine Heap-Allokation
+und eine RefCell, beim Durchlaufen wird für jedes Element ein neuer Knoten geladen.
+
+Hier speichert jeder Knoten ein kleines sortiertes Array mit bis zu N Werten (Standard 32):
+
+    [1 3 4 8] <-> [9 12 15] <-> [17 20 21 30]
+
+push:     passenden Knoten suchen, im Array einsortieren. Ist das Array voll, wird der Knoten
+          in zwei halbe Knoten geteilt.
+remove:   Wert aus dem Array entfernen. Wird ein Knoten zu klein, wird er mit dem Nachbarn
+          zusammengelegt (wenn beide zusammen in einen Knoten passen).
+contains: Knoten anhand des größten Wertes überspringen, im Knoten binäre Suche.
+
+Die Verkettung ist wie bei DLList (Rc nach rechts, Weak nach links).
+*/
+type UnrolledLink<T> = Rc<RefCell<UnrolledNode<T>>>;
+type WeakUnrolledLink<T> = Weak<RefCell<UnrolledNode<T>>>;
+
+struct UnrolledNode<T> {
+    items: Vec<T>,
+    next: Option<UnrolledLink<T>>,
+    prev: Option<WeakUnrolledLink<T>>,
+}
+
+impl<T> UnrolledNode<T> {
+    fn new(items: Vec<T>) -> UnrolledLink<T> {
+        Rc::new(RefCell::new(Self {
+            items,
+            next: None,
+            prev: None,
+        }))
+    }
+}
+
+pub struct UnrolledList<T, const N: usize = 32> {
+    head: Option<UnrolledLink<T>>,
+    tail: Option<UnrolledLink<T>>,
+    len: usize,
+}
+
+impl<T, const N: usize> UnrolledList<T, N> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    //Anzahl der Knoten (für Tests und Benchmarks)
+    pub fn node_count(&self) -> usize {
+        let mut count = 0;
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            count += 1;
+            current = node.borrow().next.clone();
+        }
+
+        count
+    }
+
+    pub fn iter(&self) -> UnrolledIter<T> {
+        UnrolledIter {
+            node: self.head.clone(),
+            index: 0,
+        }
+    }
+
+    //Neuen Knoten hinter node einhängen
+    fn insert_after(&mut self, node: &UnrolledLink<T>, new_node: UnrolledLink<T>) {
+        let next = node.borrow_mut().next.take();
+
+        new_node.borrow_mut().prev = Some(Rc::downgrade(node));
+        new_node.borrow_mut().next = next.clone();
+
+        match next {
+            Some(next_node) => next_node.borrow_mut().prev = Some(Rc::downgrade(&new_node)),
+            None => self.tail = Some(new_node.clone()),
+        }
+        node.borrow_mut().next = Some(new_node);
+    }
+
+    //Knoten aushängen (wird benutzt, wenn er leer ist oder zusammengelegt wurde)
+    fn unlink(&mut self, node: &UnrolledLink<T>) {
+        let prev = node
+            .borrow_mut()
+            .prev
+            .take()
+            .and_then(|weak| weak.upgrade());
+        let next = node.borrow_mut().next.take();
+
+        match &prev {
+            Some(node_before) => node_before.borrow_mut().next = next.clone(),
+            None => self.head = next.clone(),
+        }
+        match &next {
+            Some(node_after) => node_after.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev,
+        }
+    }
+
+    //Zu kleinen Knoten mit dem rechten Nachbarn zusammenlegen, wenn beide in einen Knoten passen
+    fn merge_with_next(&mut self, node: &UnrolledLink<T>) {
+        let next = match node.borrow().next.clone() {
+            Some(next) => next,
+            None => return,
+        };
+
+        if node.borrow().items.len() + next.borrow().items.len() > N {
+            return;
+        }
+
+        let moved: Vec<T> = next.borrow_mut().items.drain(..).collect();
+        node.borrow_mut().items.extend(moved);
+        self.unlink(&next);
+    }
+
+    //Nach dem Entfernen: leere Knoten aushängen, zu kleine Knoten zusammenlegen
+    fn rebalance(&mut self, node: &UnrolledLink<T>) {
+        let node_len = node.borrow().items.len();
+
+        if node_len == 0 {
+            self.unlink(node);
+            return;
+        }
+
+        if node_len < N / 2 {
+            self.merge_with_next(node);
+
+            let prev = node.borrow().prev.as_ref().and_then(|weak| weak.upgrade());
+            if let Some(prev) = prev {
+                self.merge_with_next(&prev);
+            }
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let node = self.head.clone()?;
+        let item = node.borrow_mut().items.remove(0);
+        self.len -= 1;
+        self.rebalance(&node);
+        Some(item)
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let node = self.tail.clone()?;
+        let item = node.borrow_mut().items.pop()?;
+        self.len -= 1;
+        self.rebalance(&node);
+        Some(item)
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_vec(&mut self) -> Vec<T> {
+        let mut out_vec = Vec::with_capacity(self.len);
+
+        //Knoten für Knoten abbauen, die Arrays können direkt übernommen werden
+        while let Some(node) = self.head.clone() {
+            out_vec.append(&mut node.borrow_mut().items);
+            self.unlink(&node);
+        }
+        self.len = 0;
+
+        out_vec
+    }
+}
+
+impl<T: Ord, const N: usize> UnrolledList<T, N> {
+    pub fn new() -> Self {
+        assert!(
+            N >= 2,
+            "ein Knoten muss mindestens 2 Werte aufnehmen können"
+        );
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    //Ersten Knoten suchen, dessen größter Wert >= wert ist
+    fn find_node<Q: Ord + ?Sized>(&self, wert: &Q) -> Option<UnrolledLink<T>>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            let next = {
+                let node_ref = node.borrow();
+                match node_ref.items.last() {
+                    Some(last) if compare(last, wert) != Ordering::Less => None,
+                    _ => node_ref.next.clone(),
+                }
+            };
+            match next {
+                None => return Some(node),
+                Some(next) => current = Some(next),
+            }
+        }
+
+        None
+    }
+
+    pub fn push(&mut self, wert: T) {
+        self.len += 1;
+
+        //Leere Liste -> ersten Knoten anlegen
+        let node = match self.find_node(&wert) {
+            Some(node) => node,
+            None => {
+                let mut items = Vec::with_capacity(N + 1);
+                items.push(wert);
+                let node = UnrolledNode::new(items);
+                self.head = Some(node.clone());
+                self.tail = Some(node);
+                return;
+            }
+        };
+
+        //find_node gibt den letzten Knoten zurück, wenn wert größer als alles ist
+        {
+            let mut node_ref = node.borrow_mut();
+            let index = node_ref.items.partition_point(|item| *item < wert);
+            node_ref.items.insert(index, wert);
+        }
+
+        //Voller Knoten wird in der Mitte geteilt
+        if node.borrow().items.len() > N {
+            let mut upper = Vec::with_capacity(N + 1);
+            {
+                let mut node_ref = node.borrow_mut();
+                let half = node_ref.items.len() / 2;
+                upper.extend(node_ref.items.drain(half..));
+            }
+            self.insert_after(&node, UnrolledNode::new(upper));
+        }
+    }
+
+    pub fn contains<Q: Ord + ?Sized>(&self, element: &Q) -> bool
+    where
+        T: borrow::Borrow<Q>,
+    {
+        match self.find_node(element) {
+            Some(node) => node
+                .borrow()
+                .items
+                .binary_search_by(|item| compare(item, element))
+                .is_ok(),
+            None => false,
+        }
+    }
+
+    pub fn remove<Q: Ord + ?Sized>(&mut self, element: &Q) -> Option<T>
+    where
+        T: borrow::Borrow<Q>,
+    {
+        let node = self.find_node(element)?;
+        let index = node
+            .borrow()
+            .items
+            .binary_search_by(|item| compare(item, element))
+            .ok()?;
+        let item = node.borrow_mut().items.remove(index);
+
+        self.len -= 1;
+        self.rebalance(&node);
+        Some(item)
+    }
+}
+
+impl<T: Ord, const N: usize> Default for UnrolledList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord, const N: usize> FromIterator<T> for UnrolledList<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = UnrolledList::new();
+        for wert in iter {
+            list.push(wert);
+        }
+        list
+    }
+}
+
+//Iterativ freigeben, damit lange Listen beim Drop keinen Stack Overflow erzeugen
+impl<T, const N: usize> Drop for UnrolledList<T, N> {
+    fn drop(&mut self) {
+        self.tail.take();
+        let mut current = self.head.take();
+
+        while let Some(node) = current {
+            current = node.borrow_mut().next.take();
+        }
+    }
+}
+
+//Gibt wie Iter bei DLList Kopien der Werte zurück
+pub struct UnrolledIter<T> {
+    node: Option<UnrolledLink<T>>,
+    index: usize,
+}
+
+impl<T: Clone> Iterator for UnrolledIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            let node = self.node.clone()?;
+            let node_ref = node.borrow();
+
+            if let Some(item) = node_ref.items.get(self.index) {
+                self.index += 1;
+                return Some(item.clone());
+            }
+
+            //Knoten fertig -> weiter zum nächsten
+            self.node = node_ref.next.clone();
+            self.index = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Kleine Knoten, damit Teilen und Zusammenlegen oft passiert
+    type SmallList = UnrolledList<i32, 4>;
+
+    //Prüft die Verkettung und dass jeder Knoten sortiert und nicht leer ist
+    fn check_links<const N: usize>(list: &UnrolledList<i32, N>) {
+        let mut current = list.head.clone();
+        let mut prev: Option<UnrolledLink<i32>> = None;
+        let mut last_item = i32::MIN;
+
+        while let Some(node) = current {
+            let node_ref = node.borrow();
+            assert!(!node_ref.items.is_empty() && node_ref.items.len() <= N);
+            for item in &node_ref.items {
+                assert!(*item >= last_item);
+                last_item = *item;
+            }
+
+            let node_prev = node_ref.prev.as_ref().and_then(|weak| weak.upgrade());
+            assert_eq!(node_prev.is_some(), prev.is_some());
+            if let (Some(a), Some(b)) = (node_prev, &prev) {
+                assert!(Rc::ptr_eq(&a, b));
+            }
+
+            current = node_ref.next.clone();
+            drop(node_ref);
+            prev = Some(node);
+        }
+
+        match (&prev, &list.tail) {
+            (Some(a), Some(b)) => assert!(Rc::ptr_eq(a, b)),
+            (None, None) => {}
+            _ => panic!("tail ist falsch"),
+        }
+    }
+
+    #[test]
+    fn sort_test_unrolled() {
+        let mut list: SmallList = [8, 6, 17, 35, 888, 1, 0, 6, 4].into_iter().collect();
+
+        check_links(&list);
+        assert_eq!(list.len(), 9);
+        assert!(list.node_count() >= 3);
+        assert_eq!(list.to_vec(), vec![0, 1, 4, 6, 6, 8, 17, 35, 888]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn empty_list_function_test_unrolled() {
+        let mut list = SmallList::new();
+
+        assert_eq!(list.to_vec(), vec![]);
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.remove(&3), None);
+        assert!(!list.contains(&3));
+    }
+
+    #[test]
+    fn pop_front_pop_back_unrolled() {
+        let mut list: SmallList = [8, 6, 17, 35, 888, 1, 0].into_iter().collect();
+
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(888));
+        assert_eq!(list.pop_back(), Some(35));
+        assert_eq!(list.pop_front(), Some(1));
+        check_links(&list);
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![6, 8, 17]);
+    }
+
+    #[test]
+    fn contains_and_remove_unrolled() {
+        let mut list: SmallList = (0..40).map(|x| x * 2).collect();
+
+        assert!(list.contains(&0));
+        assert!(list.contains(&38));
+        assert!(list.contains(&78));
+        assert!(!list.contains(&39));
+        assert!(!list.contains(&100));
+
+        //Fast alles entfernen, dabei werden Knoten zusammengelegt
+        for x in (0..40).filter(|x| x % 5 != 0) {
+            assert_eq!(list.remove(&(x * 2)), Some(x * 2));
+            check_links(&list);
+        }
+        assert_eq!(list.remove(&2), None);
+
+        assert_eq!(list.len(), 8);
+        assert!(list.node_count() <= 4);
+        assert_eq!(list.to_vec(), vec![0, 10, 20, 30, 40, 50, 60, 70]);
+    }
+
+    #[test]
+    fn stress_test_unrolled() {
+        let mut list = UnrolledList::<i32>::new();
+
+        //Absteigend und aufsteigend gemischt einfügen
+        for ele in 0..1000 {
+            list.push(if ele % 2 == 0 { ele } else { 1000 - ele });
+        }
+
+        check_links(&list);
+        assert_eq!(list.len(), 1000);
+        let mut expected: Vec<_> = (0..1000)
+            .map(|e| if e % 2 == 0 { e } else { 1000 - e })
+            .collect();
+        expected.sort();
+        assert_eq!(list.iter().collect::<Vec<_>>(), expected);
+        assert_eq!(list.to_vec(), expected);
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::{DLList, Link, get_next, get_prev};
+
+/*Gleitendes Fenster mit Ordnungsstatistiken (laufender Median)
+
+SortedWindow hält die letzten N Werte eines Datenstroms sortiert in einer DLList.
+Zusätzlich werden die Knoten in Ankunftsreihenfolge in einer FIFO (VecDeque) gemerkt:
+
+    FIFO:   [k7, k3, k9]     (älteste zuerst)
+    DLList:  3 <-> 7 <-> 9
+
+Kommt ein neuer Wert und das Fenster ist voll, wird der älteste Knoten aus der FIFO genommen
+und direkt aus der Liste ausgehängt (ohne Suche). Dadurch muss nicht für jeden Wert ein Vec
+kopiert und sortiert werden.
+
+median(), percentile(p), min() und max() lesen die Werte aus der sortierten Liste.
+min/max sind O(1) (head/tail), Median und Perzentil laufen vom näheren Ende bis zum Index.
+*/
+pub struct SortedWindow<T> {
+    list: DLList<T>,
+    fifo: VecDeque<Link<T>>,
+    size: usize,
+}
+
+impl<T: Ord + Clone> SortedWindow<T> {
+    pub fn new(size: usize) -> Self {
+        assert!(
+            size > 0,
+            "das Fenster muss mindestens einen Wert aufnehmen können"
+        );
+        Self {
+            //Der Pool verhindert, dass für jeden neuen Wert Speicher angefordert werden muss
+            list: DLList::with_capacity(size + 1),
+            fifo: VecDeque::with_capacity(size + 1),
+            size,
+        }
+    }
+
+    //Neuen Wert aufnehmen, gibt den herausgefallenen ältesten Wert zurück
+    pub fn push_sample(&mut self, wert: T) -> Option<T> {
+        let node = self.list.push_node(wert);
+        self.fifo.push_back(node);
+
+        if self.fifo.len() <= self.size {
+            return None;
+        }
+
+        let oldest = self.fifo.pop_front()?;
+        self.list.unlink_node(&oldest);
+        Some(self.list.pool.free(oldest))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.list
+            .head
+            .as_ref()
+            .map(|node| node.borrow().item.clone())
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.list
+            .tail
+            .as_ref()
+            .map(|node| node.borrow().item.clone())
+    }
+
+    //Unterer Median, bei gerader Anzahl der kleinere der beiden mittleren Werte
+    pub fn median(&self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.nth((self.len() - 1) / 2)
+    }
+
+    //Perzentil nach der Nearest-Rank-Methode, p liegt zwischen 0 und 100
+    pub fn percentile(&self, p: f64) -> Option<T> {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "p muss zwischen 0 und 100 liegen"
+        );
+        if self.is_empty() {
+            return None;
+        }
+
+        //Aufrunden ohne f64::ceil, das es ohne std nicht gibt
+        let exact = p / 100.0 * self.len() as f64;
+        let mut rank = exact as usize;
+        if (rank as f64) < exact {
+            rank += 1;
+        }
+        self.nth(rank.saturating_sub(1))
+    }
+
+    //Wert an Position index (0 = kleinster Wert), läuft vom näheren Ende aus
+    fn nth(&self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let node = if index < self.len() / 2 {
+            let mut node = self.list.head.clone()?;
+            for _ in 0..index {
+                node = get_next(&node)?;
+            }
+            node
+        } else {
+            let mut node = self.list.tail.clone()?;
+            for _ in index + 1..self.len() {
+                node = get_prev(&node)?.upgrade()?;
+            }
+            node
+        };
+
+        let item = node.borrow().item.clone();
+        Some(item)
+    }
+
+    pub fn len(&self) -> usize {
+        self.fifo.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fifo.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.fifo.len() == self.size
+    }
+
+    //Werte im Fenster, sortiert
+    pub fn sorted(&self) -> Vec<T> {
+        self.list.iter().collect()
+    }
+
+    //Werte im Fenster in Ankunftsreihenfolge
+    pub fn samples(&self) -> Vec<T> {
+        self.fifo
+            .iter()
+            .map(|node| node.borrow().item.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    //Vergleichswert: Fenster kopieren und sortieren
+    fn naive_median(values: &[i32]) -> i32 {
+        let mut sorted = values.to_vec();
+        sorted.sort();
+        sorted[(sorted.len() - 1) / 2]
+    }
+
+    #[test]
+    fn running_median() {
+        let stream = [5, 1, 9, 3, 7, 7, 2, 8, 0, 6, 4, 4];
+        let mut window = SortedWindow::new(4);
+
+        for (i, wert) in stream.iter().enumerate() {
+            let evicted = window.push_sample(*wert);
+            if i >= 4 {
+                assert_eq!(evicted, Some(stream[i - 4]));
+            } else {
+                assert_eq!(evicted, None);
+            }
+
+            let start = (i + 1).saturating_sub(4);
+            let current = &stream[start..=i];
+            assert_eq!(window.median(), Some(naive_median(current)));
+            assert_eq!(window.min(), current.iter().min().copied());
+            assert_eq!(window.max(), current.iter().max().copied());
+        }
+
+        assert_eq!(window.samples(), vec![0, 6, 4, 4]);
+        assert_eq!(window.sorted(), vec![0, 4, 4, 6]);
+    }
+
+    #[test]
+    fn percentiles() {
+        let mut window = SortedWindow::new(10);
+        for wert in (1..=10).rev() {
+            window.push_sample(wert * 10);
+        }
+
+        assert!(window.is_full());
+        assert_eq!(window.percentile(0.0), Some(10));
+        assert_eq!(window.percentile(10.0), Some(10));
+        assert_eq!(window.percentile(25.0), Some(30));
+        assert_eq!(window.percentile(50.0), Some(50));
+        assert_eq!(window.percentile(90.0), Some(90));
+        assert_eq!(window.percentile(100.0), Some(100));
+    }
+
+    #[test]
+    fn empty_window() {
+        let window = SortedWindow::<i32>::new(3);
+
+        assert_eq!(window.median(), None);
+        assert_eq!(window.percentile(50.0), None);
+        assert_eq!(window.min(), None);
+        assert_eq!(window.max(), None);
+    }
+
+    #[test]
+    fn window_of_one() {
+        let mut window = SortedWindow::new(1);
+
+        assert_eq!(window.push_sample(3), None);
+        assert_eq!(window.push_sample(1), Some(3));
+        assert_eq!(window.median(), Some(1));
+        assert_eq!(window.len(), 1);
+    }
+
+    #[test]
+    fn nodes_are_reused() {
+        let mut window = SortedWindow::new(8);
+        for wert in 0..1000 {
+            window.push_sample(wert % 17);
+        }
+
+        assert_eq!(window.list.pool_stats().misses, 0);
+        assert_eq!(window.len(), 8);
+    }
+}
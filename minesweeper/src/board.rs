@@ -0,0 +1,261 @@
+use std::fmt;
+
+use crate::{MINE, NO_MINE};
+
+/*Eingabe prüfen
+
+annotate und minefield_calculation gingen davon aus, dass alle Zeilen gleich lang sind und nur
+'*' und ' ' enthalten. Bei unterschiedlich langen Zeilen ist add_spacer_to_minefield mit einem
+Index außerhalb des Feldes abgestürzt, andere Zeichen wurden einfach übernommen.
+
+parse_board prüft die Eingabe einmal am Anfang und gibt ein Board zurück. Alles, was ein Board
+bekommt, kann sich danach auf ein rechteckiges Feld aus MINE und NO_MINE verlassen.
+
+Die Fehler enthalten die Position wie in einem Editor, Zeile und Spalte beginnen also bei 1:
+
+    " * "
+    " x "   -> InvalidCharacter { line: 2, column: 2, found: 'x' }
+    "  "    -> RaggedRow { line: 3, expected: 3, found: 2 }
+
+Eine Eingabe ohne Zeilen ist erlaubt (leeres Feld), ebenso Zeilen, die alle leer sind (Feld mit
+Breite 0, z.B. system_test/test_files/test_field_5x0_0_mines). Steht eine leere Zeile neben
+nicht leeren Zeilen, ist das ein EmptyRow Fehler mit der Nummer der (ersten) leeren Zeile.
+*/
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BoardError {
+    //Zeile ist anders lang als die erste Zeile
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    InvalidCharacter {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    EmptyRow {
+        line: usize,
+    },
+}
+
+impl fmt::Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoardError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Zeile {}: {} Zeichen statt {} wie in der ersten Zeile",
+                line, found, expected
+            ),
+            BoardError::InvalidCharacter {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "Zeile {}, Spalte {}: ungültiges Zeichen {:?}, erlaubt sind '*' und ' '",
+                line, column, found
+            ),
+            BoardError::EmptyRow { line } => write!(f, "Zeile {}: die Zeile ist leer", line),
+        }
+    }
+}
+
+impl std::error::Error for BoardError {}
+
+//Geprüftes, rechteckiges Minenfeld
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Board {
+    cells: Vec<Vec<u8>>,
+}
+
+impl Board {
+    pub fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cells.first().map_or(0, |row| row.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn mine_count(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|&&cell| cell == MINE)
+            .count()
+    }
+
+    //Zeilen als Bytes, nur MINE und NO_MINE
+    pub(crate) fn cells(&self) -> &[Vec<u8>] {
+        &self.cells
+    }
+}
+
+//Die bis zu 8 Nachbarn eines Feldes in einem Feld mit rows x columns, für Spiel und Solver.
+//Bei einem leeren Feld (0 Zeilen oder 0 Spalten, siehe parse_board) gibt es keine Nachbarn.
+pub(crate) fn neighbours(
+    rows: usize,
+    columns: usize,
+    row: usize,
+    column: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    //Halboffene Bereiche, bei 0 Zeilen oder Spalten sind sie leer (kein Unterlauf wie bei rows - 1)
+    (row.saturating_sub(1)..(row + 2).min(rows))
+        .flat_map(move |r| {
+            (column.saturating_sub(1)..(column + 2).min(columns)).map(move |c| (r, c))
+        })
+        .filter(move |&position| position != (row, column))
+}
+
+pub fn parse_board(minefield: &[&str]) -> Result<Board, BoardError> {
+    let mut cells: Vec<Vec<u8>> = Vec::with_capacity(minefield.len());
+
+    for (index, text) in minefield.iter().enumerate() {
+        let line = index + 1;
+        let mut row = Vec::with_capacity(text.len());
+
+        //Über chars laufen, damit die Spalte auch bei Umlauten o.ä. stimmt
+        for (column, found) in text.chars().enumerate() {
+            match found {
+                '*' => row.push(MINE),
+                ' ' => row.push(NO_MINE),
+                _ => {
+                    return Err(BoardError::InvalidCharacter {
+                        line,
+                        column: column + 1,
+                        found,
+                    });
+                }
+            }
+        }
+
+        if let Some(first) = cells.first()
+            && first.len() != row.len()
+        {
+            //Leere und nicht leere Zeilen gemischt, die erste Zeile bestimmt die Breite
+            if first.is_empty() {
+                return Err(BoardError::EmptyRow { line: 1 });
+            }
+            if row.is_empty() {
+                return Err(BoardError::EmptyRow { line });
+            }
+            return Err(BoardError::RaggedRow {
+                line,
+                expected: first.len(),
+                found: row.len(),
+            });
+        }
+
+        cells.push(row);
+    }
+
+    Ok(Board { cells })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_board() {
+        let board = parse_board(&[" * ", "** "]).unwrap();
+
+        assert_eq!(board.rows(), 2);
+        assert_eq!(board.columns(), 3);
+        assert_eq!(board.mine_count(), 3);
+        assert_eq!(board.cells(), [b" * ".to_vec(), b"** ".to_vec()]);
+    }
+
+    #[test]
+    fn parse_empty_input() {
+        let board = parse_board(&[]).unwrap();
+
+        assert!(board.is_empty());
+        assert_eq!(board.columns(), 0);
+    }
+
+    #[test]
+    fn ragged_row() {
+        assert_eq!(
+            parse_board(&["   ", "   ", "    "]),
+            Err(BoardError::RaggedRow {
+                line: 3,
+                expected: 3,
+                found: 4
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_character() {
+        assert_eq!(
+            parse_board(&["  ", " 1"]),
+            Err(BoardError::InvalidCharacter {
+                line: 2,
+                column: 2,
+                found: '1'
+            })
+        );
+        //Spalte zählt Zeichen, nicht Bytes
+        assert_eq!(
+            parse_board(&["ä*"]),
+            Err(BoardError::InvalidCharacter {
+                line: 1,
+                column: 1,
+                found: 'ä'
+            })
+        );
+        assert_eq!(
+            parse_board(&["*", "\t"]).unwrap_err().to_string(),
+            "Zeile 2, Spalte 1: ungültiges Zeichen '\\t', erlaubt sind '*' und ' '"
+        );
+    }
+
+    #[test]
+    fn empty_row() {
+        assert_eq!(
+            parse_board(&[" ", ""]),
+            Err(BoardError::EmptyRow { line: 2 })
+        );
+        assert_eq!(
+            parse_board(&["", "", "*"]),
+            Err(BoardError::EmptyRow { line: 1 })
+        );
+    }
+
+    #[test]
+    fn neighbours_in_board() {
+        let mut corner: Vec<_> = neighbours(2, 3, 0, 0).collect();
+        corner.sort();
+        assert_eq!(corner, vec![(0, 1), (1, 0), (1, 1)]);
+        assert_eq!(neighbours(3, 3, 1, 1).count(), 8);
+        assert_eq!(neighbours(1, 1, 0, 0).count(), 0);
+    }
+
+    #[test]
+    fn neighbours_in_empty_board() {
+        assert_eq!(neighbours(4, 0, 0, 0).count(), 0);
+        assert_eq!(neighbours(0, 0, 0, 0).count(), 0);
+        assert_eq!(neighbours(0, 3, 0, 1).count(), 0);
+    }
+
+    #[test]
+    fn zero_width_board() {
+        let board = parse_board(&["", "", "", ""]).unwrap();
+
+        assert!(!board.is_empty());
+        assert_eq!(board.rows(), 4);
+        assert_eq!(board.columns(), 0);
+        assert_eq!(board.mine_count(), 0);
+    }
+}
@@ -0,0 +1,531 @@
+use std::fmt;
+
+use crate::board::{self, Board, BoardError, parse_board};
+use crate::probability::{Probabilities, mine_probabilities};
+use crate::solve::{Cell, View};
+use crate::{MINE, NO_MINE, annotate_board};
+
+/*Spielbares Minesweeper
+
+annotate berechnet zu einem vollständig bekannten Minenfeld die Zahlen. Für ein Spiel wird das
+Feld einmal am Anfang mit annotate ausgewertet, danach sieht der Spieler nur die Felder, die er
+aufgedeckt hat:
+
+    Lösung (annotate)      Spieler sieht
+    1*3*1                  ..3..
+    13*31                  1F...
+     2*2                   .....
+
+Jede Zelle hat einen Zustand:
+    Hidden   -> noch verdeckt
+    Revealed -> aufgedeckt, die Zahl (oder Mine) ist sichtbar
+    Flagged  -> vom Spieler als Mine markiert, kann nicht aufgedeckt werden
+
+Das Spiel ist verloren, sobald eine Mine aufgedeckt wird, und gewonnen, sobald alle Felder ohne
+Mine aufgedeckt sind. Flaggen sind dafür nicht nötig. Nach Spielende sind keine Züge mehr möglich.
+
+Aufdecken von leeren Bereichen (Flood-Fill):
+    Hat ein aufgedecktes Feld keine Mine in der Nachbarschaft (' ' bei annotate), können alle
+    Nachbarn gefahrlos aufgedeckt werden. Ist ein Nachbar wieder leer, geht es dort weiter.
+    So wird der ganze zusammenhängende leere Bereich samt Rand aus Zahlen auf einmal aufgedeckt:
+
+        Lösung         nach Klick oben rechts
+        |     |        |     |
+        |111  |   =>   |111  |
+        |1*1  |        |..1  |
+        |111  |        |..1  |
+
+    Das passiert mit einem eigenen Stack statt mit Rekursion, damit auch große Felder
+    keinen Stack Overflow erzeugen. Geflaggte Felder werden dabei übersprungen.
+
+Chording:
+    Auf einer aufgedeckten Zahl, um die genau so viele Flaggen liegen wie die Zahl angibt,
+    werden alle übrigen verdeckten Nachbarn auf einmal aufgedeckt. Ist eine Flagge falsch
+    gesetzt, wird dabei eine Mine aufgedeckt und das Spiel ist verloren.
+*/
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CellState {
+    Hidden,
+    Revealed,
+    Flagged,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameState {
+    Running,
+    Won,
+    Lost,
+}
+
+//Gründe, warum ein Zug nicht ausgeführt wurde
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveError {
+    OutOfBounds,
+    GameOver,
+    AlreadyRevealed,
+    Flagged,
+    //Chording nur auf aufgedeckten Zahlen mit passender Anzahl an Flaggen
+    NotRevealed,
+    FlagCountMismatch,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            MoveError::OutOfBounds => "das Feld liegt außerhalb des Minenfelds",
+            MoveError::GameOver => "das Spiel ist bereits beendet",
+            MoveError::AlreadyRevealed => "das Feld ist bereits aufgedeckt",
+            MoveError::Flagged => "das Feld ist mit einer Flagge markiert",
+            MoveError::NotRevealed => "das Feld ist noch nicht aufgedeckt",
+            MoveError::FlagCountMismatch => "die Anzahl der Flaggen passt nicht zur Zahl",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+pub struct Game {
+    //Ergebnis von annotate: b'*', b' ' oder b'1'..=b'8'
+    solution: Vec<Vec<u8>>,
+    cells: Vec<Vec<CellState>>,
+    state: GameState,
+    //Felder ohne Mine, die noch nicht aufgedeckt sind
+    hidden_safe: usize,
+    //Anzahl aller Minen im Feld, wird einmal beim Anlegen gezählt
+    mines: usize,
+    moves: usize,
+}
+
+impl Game {
+    //Neues Spiel zu einem Minenfeld im Format von annotate (' ' = frei, '*' = Mine)
+    pub fn new(minefield: &[&str]) -> Result<Self, BoardError> {
+        let board = parse_board(minefield)?;
+        Ok(Self::from_board(&board))
+    }
+
+    pub fn from_board(board: &Board) -> Self {
+        let solution: Vec<Vec<u8>> = annotate_board(board)
+            .into_iter()
+            .map(|row| row.into_bytes())
+            .collect();
+
+        let cells = vec![vec![CellState::Hidden; board.columns()]; board.rows()];
+        let mines = board.mine_count();
+        let hidden_safe = board.rows() * board.columns() - mines;
+
+        //Ohne freie Felder gibt es nichts aufzudecken
+        let state = if hidden_safe == 0 {
+            GameState::Won
+        } else {
+            GameState::Running
+        };
+
+        Self {
+            solution,
+            cells,
+            state,
+            hidden_safe,
+            mines,
+            moves: 0,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.solution.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.solution.first().map_or(0, |row| row.len())
+    }
+
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+
+    //Anzahl der ausgeführten Züge (aufdecken und Flaggen setzen/entfernen)
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    pub fn cell_state(&self, row: usize, column: usize) -> Option<CellState> {
+        self.cells.get(row)?.get(column).copied()
+    }
+
+    //Deckt ein Feld auf und gibt den Spielstand danach zurück
+    pub fn reveal(&mut self, row: usize, column: usize) -> Result<GameState, MoveError> {
+        match self.check_move(row, column)? {
+            CellState::Revealed => return Err(MoveError::AlreadyRevealed),
+            CellState::Flagged => return Err(MoveError::Flagged),
+            CellState::Hidden => {}
+        }
+
+        self.moves += 1;
+        self.open(row, column);
+        self.update_won();
+        Ok(self.state)
+    }
+
+    //Deckt alle verdeckten Nachbarn einer Zahl auf, wenn die Flaggen rundherum dazu passen.
+    //Zählt als ein Zug, außer es gibt keinen verdeckten Nachbarn.
+    pub fn chord(&mut self, row: usize, column: usize) -> Result<GameState, MoveError> {
+        if self.check_move(row, column)? != CellState::Revealed {
+            return Err(MoveError::NotRevealed);
+        }
+
+        let flags = self
+            .neighbours(row, column)
+            .filter(|&(r, c)| self.cells[r][c] == CellState::Flagged)
+            .count();
+        let number = match self.solution[row][column] {
+            NO_MINE => 0,
+            value => (value - b'0') as usize,
+        };
+        if flags != number {
+            return Err(MoveError::FlagCountMismatch);
+        }
+
+        let hidden: Vec<(usize, usize)> = self
+            .neighbours(row, column)
+            .filter(|&(r, c)| self.cells[r][c] == CellState::Hidden)
+            .collect();
+        //Nichts aufzudecken, dann ändert sich nichts und es zählt auch nicht als Zug
+        if hidden.is_empty() {
+            return Ok(self.state);
+        }
+
+        self.moves += 1;
+        for (r, c) in hidden {
+            //Ein Nachbar kann schon durch den Flood-Fill eines anderen aufgedeckt sein
+            if self.cells[r][c] == CellState::Hidden {
+                self.open(r, c);
+            }
+        }
+        self.update_won();
+        Ok(self.state)
+    }
+
+    //Setzt oder entfernt eine Flagge, gibt den neuen Zustand des Feldes zurück
+    pub fn toggle_flag(&mut self, row: usize, column: usize) -> Result<CellState, MoveError> {
+        let new_state = match self.check_move(row, column)? {
+            CellState::Revealed => return Err(MoveError::AlreadyRevealed),
+            CellState::Flagged => CellState::Hidden,
+            CellState::Hidden => CellState::Flagged,
+        };
+
+        self.moves += 1;
+        self.cells[row][column] = new_state;
+        Ok(new_state)
+    }
+
+    //Anzahl der gesetzten Flaggen
+    pub fn flag_count(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|&&cell| cell == CellState::Flagged)
+            .count()
+    }
+
+    //Sicht des Spielers: '.' verdeckt, 'F' Flagge, sonst das Zeichen aus annotate
+    pub fn render(&self) -> Vec<String> {
+        self.solution
+            .iter()
+            .zip(&self.cells)
+            .map(|(solution_row, cell_row)| {
+                solution_row
+                    .iter()
+                    .zip(cell_row)
+                    .map(|(&value, cell)| match cell {
+                        CellState::Hidden => '.',
+                        CellState::Flagged => 'F',
+                        CellState::Revealed => value as char,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    //Deckt ein verdecktes Feld auf, bei einem leeren Feld auch den ganzen leeren Bereich
+    fn open(&mut self, row: usize, column: usize) {
+        self.cells[row][column] = CellState::Revealed;
+        if self.solution[row][column] == MINE {
+            self.state = GameState::Lost;
+            return;
+        }
+        self.hidden_safe -= 1;
+
+        //Stack mit aufgedeckten leeren Feldern, deren Nachbarn noch geprüft werden müssen
+        let mut stack = Vec::new();
+        if self.solution[row][column] == NO_MINE {
+            stack.push((row, column));
+        }
+
+        while let Some((r, c)) = stack.pop() {
+            let neighbours: Vec<(usize, usize)> = self.neighbours(r, c).collect();
+            for (nr, nc) in neighbours {
+                if self.cells[nr][nc] != CellState::Hidden {
+                    continue;
+                }
+                //Nachbarn eines leeren Feldes sind nie Minen
+                self.cells[nr][nc] = CellState::Revealed;
+                self.hidden_safe -= 1;
+                if self.solution[nr][nc] == NO_MINE {
+                    stack.push((nr, nc));
+                }
+            }
+        }
+    }
+
+    fn update_won(&mut self) {
+        if self.state == GameState::Running && self.hidden_safe == 0 {
+            self.state = GameState::Won;
+        }
+    }
+
+    fn neighbours(&self, row: usize, column: usize) -> impl Iterator<Item = (usize, usize)> {
+        board::neighbours(self.rows(), self.columns(), row, column)
+    }
+
+    //Sichtbarer Zustand für den Solver. Flaggen und aufgedeckte Minen zählen als verdeckt.
+    pub fn view(&self) -> View {
+        let cells = self
+            .solution
+            .iter()
+            .zip(&self.cells)
+            .map(|(solution_row, cell_row)| {
+                solution_row
+                    .iter()
+                    .zip(cell_row)
+                    .map(|(&value, &cell)| match (cell, value) {
+                        (CellState::Revealed, NO_MINE) => Cell::Number(0),
+                        (CellState::Revealed, value) if value != MINE => Cell::Number(value - b'0'),
+                        _ => Cell::Hidden,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        View::new(cells)
+    }
+
+    //Anzahl aller Minen im Feld, ist dem Spieler wie im echten Spiel bekannt
+    pub fn mine_count(&self) -> usize {
+        self.mines
+    }
+
+    //Mine-Wahrscheinlichkeit jedes verdeckten Feldes, berechnet nur aus der sichtbaren Ansicht
+    pub fn mine_probabilities(&self) -> Option<Probabilities> {
+        mine_probabilities(&self.view(), self.mine_count())
+    }
+
+    //Verdecktes Feld mit der kleinsten Wahrscheinlichkeit für eine Mine (Tipp für Spieler und Bots)
+    pub fn safest_guess(&self) -> Option<(usize, usize)> {
+        if self.state != GameState::Running {
+            return None;
+        }
+        self.mine_probabilities()?.safest()
+    }
+
+    //Gemeinsame Prüfung für alle Züge, gibt den aktuellen Zustand des Feldes zurück
+    fn check_move(&self, row: usize, column: usize) -> Result<CellState, MoveError> {
+        if self.state != GameState::Running {
+            return Err(MoveError::GameOver);
+        }
+        self.cell_state(row, column).ok_or(MoveError::OutOfBounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small_game() -> Game {
+        Game::new(&[" * ", "   "]).unwrap()
+    }
+
+    #[test]
+    fn new_game_is_hidden() {
+        let game = small_game();
+
+        assert_eq!(game.rows(), 2);
+        assert_eq!(game.columns(), 3);
+        assert_eq!(game.state(), GameState::Running);
+        assert_eq!(game.moves(), 0);
+        assert_eq!(game.render(), vec!["...", "..."]);
+    }
+
+    #[test]
+    fn reveal_shows_annotated_numbers() {
+        let mut game = small_game();
+
+        assert_eq!(game.reveal(0, 0), Ok(GameState::Running));
+        assert_eq!(game.reveal(1, 1), Ok(GameState::Running));
+        assert_eq!(game.render(), vec!["1..", ".1."]);
+        assert_eq!(game.cell_state(0, 0), Some(CellState::Revealed));
+        assert_eq!(game.moves(), 2);
+    }
+
+    #[test]
+    fn reveal_mine_loses() {
+        let mut game = small_game();
+
+        assert_eq!(game.reveal(0, 1), Ok(GameState::Lost));
+        assert_eq!(game.render(), vec![".*.", "..."]);
+        assert_eq!(game.reveal(0, 0), Err(MoveError::GameOver));
+        assert_eq!(game.toggle_flag(0, 0), Err(MoveError::GameOver));
+        assert_eq!(game.moves(), 1);
+    }
+
+    #[test]
+    fn reveal_all_safe_cells_wins() {
+        let mut game = small_game();
+
+        for (row, column) in [(0, 0), (0, 2), (1, 0), (1, 1)] {
+            assert_eq!(game.reveal(row, column), Ok(GameState::Running));
+        }
+        assert_eq!(game.reveal(1, 2), Ok(GameState::Won));
+        assert_eq!(game.render(), vec!["1.1", "111"]);
+        assert_eq!(game.reveal(0, 1), Err(MoveError::GameOver));
+    }
+
+    #[test]
+    fn flags_block_reveal() {
+        let mut game = small_game();
+
+        assert_eq!(game.toggle_flag(0, 1), Ok(CellState::Flagged));
+        assert_eq!(game.reveal(0, 1), Err(MoveError::Flagged));
+        assert_eq!(game.flag_count(), 1);
+        assert_eq!(game.render(), vec![".F.", "..."]);
+
+        assert_eq!(game.toggle_flag(0, 1), Ok(CellState::Hidden));
+        assert_eq!(game.flag_count(), 0);
+        assert_eq!(game.moves(), 2);
+    }
+
+    #[test]
+    fn invalid_moves() {
+        let mut game = small_game();
+        game.reveal(1, 0).unwrap();
+
+        assert_eq!(game.reveal(1, 0), Err(MoveError::AlreadyRevealed));
+        assert_eq!(game.toggle_flag(1, 0), Err(MoveError::AlreadyRevealed));
+        assert_eq!(game.reveal(2, 0), Err(MoveError::OutOfBounds));
+        assert_eq!(game.toggle_flag(0, 3), Err(MoveError::OutOfBounds));
+        //Ungültige Züge werden nicht gezählt
+        assert_eq!(game.moves(), 1);
+    }
+
+    #[test]
+    fn board_without_safe_cells_is_won() {
+        assert_eq!(Game::new(&["**"]).unwrap().state(), GameState::Won);
+        assert_eq!(Game::new(&[]).unwrap().state(), GameState::Won);
+    }
+
+    #[test]
+    fn probabilities_on_game() {
+        let mut game = small_game();
+        game.reveal(1, 1).unwrap();
+
+        //Eine Mine unter fünf gleichwertigen verdeckten Feldern
+        let probabilities = game.mine_probabilities().unwrap();
+        assert_eq!(game.mine_count(), 1);
+        assert!((probabilities.get(0, 0).unwrap() - 0.2).abs() < 1e-9);
+        assert_eq!(probabilities.get(1, 1), None);
+        //Außerhalb des Feldes wie bei cell_state None statt Panik
+        assert_eq!(probabilities.get(2, 0), None);
+        assert_eq!(probabilities.get(0, 3), None);
+
+        game.toggle_flag(0, 1).unwrap();
+        game.reveal(0, 0).unwrap();
+        game.reveal(0, 2).unwrap();
+        //Die 1 oben links lässt nur (0, 1) zu
+        assert_eq!(game.safest_guess(), Some((1, 0)));
+        game.reveal(1, 0).unwrap();
+        game.reveal(1, 2).unwrap();
+        assert_eq!(game.safest_guess(), None);
+    }
+
+    #[test]
+    fn invalid_board_is_rejected() {
+        assert_eq!(
+            Game::new(&["* ", "*"]).err(),
+            Some(BoardError::RaggedRow {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn reveal_empty_region_cascades() {
+        let mut game = Game::new(&["     ", "     ", " *   ", "     "]).unwrap();
+
+        assert_eq!(game.reveal(0, 4), Ok(GameState::Running));
+        assert_eq!(game.render(), vec!["     ", "111  ", "..1  ", "..1  "]);
+        assert_eq!(game.moves(), 1);
+
+        assert_eq!(game.reveal(2, 0), Ok(GameState::Running));
+        assert_eq!(game.reveal(3, 0), Ok(GameState::Running));
+        assert_eq!(game.reveal(3, 1), Ok(GameState::Won));
+    }
+
+    #[test]
+    fn flood_fill_skips_flags() {
+        let mut game = Game::new(&["    ", "   *"]).unwrap();
+        game.toggle_flag(0, 0).unwrap();
+
+        game.reveal(1, 0).unwrap();
+        assert_eq!(game.render(), vec!["F 1.", "  1."]);
+        assert_eq!(game.state(), GameState::Running);
+    }
+
+    #[test]
+    fn flood_fill_large_board() {
+        //Ohne eigenen Stack würde die Rekursion hier sehr tief werden
+        let row = " ".repeat(400);
+        let rows: Vec<&str> = vec![row.as_str(); 400];
+        let mut game = Game::new(&rows).unwrap();
+
+        assert_eq!(game.reveal(200, 200), Ok(GameState::Won));
+        assert_eq!(game.moves(), 1);
+    }
+
+    #[test]
+    fn chord_reveals_neighbours() {
+        let mut game = small_game();
+        game.reveal(1, 1).unwrap();
+
+        assert_eq!(game.chord(1, 1), Err(MoveError::FlagCountMismatch));
+        assert_eq!(game.chord(0, 0), Err(MoveError::NotRevealed));
+
+        game.toggle_flag(0, 1).unwrap();
+        assert_eq!(game.chord(1, 1), Ok(GameState::Won));
+        assert_eq!(game.render(), vec!["1F1", "111"]);
+        assert_eq!(game.moves(), 3);
+    }
+
+    #[test]
+    fn chord_with_wrong_flag_loses() {
+        let mut game = small_game();
+        game.reveal(1, 1).unwrap();
+        game.toggle_flag(0, 0).unwrap();
+
+        assert_eq!(game.chord(1, 1), Ok(GameState::Lost));
+        assert_eq!(game.cell_state(0, 1), Some(CellState::Revealed));
+    }
+
+    #[test]
+    fn chord_without_hidden_neighbours_is_no_move() {
+        let mut game = Game::new(&["   ", "  *"]).unwrap();
+        game.reveal(0, 0).unwrap();
+        assert_eq!(game.moves(), 1);
+
+        //Alle Nachbarn von (1, 0) sind schon aufgedeckt
+        assert_eq!(game.chord(1, 0), Ok(GameState::Running));
+        assert_eq!(game.moves(), 1);
+        assert_eq!(game.render(), vec![" 1.", " 1."]);
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+
+use crate::rng::Lcg;
+use crate::{MINE, NO_MINE};
+
+/*Zufällige Minenfelder
+
+Erzeugt ein Minenfeld im gleichen Format, das annotate erwartet ('*' = Mine, ' ' = frei).
+Die Anzahl der Minen wird entweder direkt angegeben oder als Dichte (Anteil der Felder).
+
+Der Zufall kommt aus einem eigenen LCG mit Startwert (Seed). Gleicher Seed und gleiche
+Einstellungen ergeben immer dasselbe Feld, so kann ein Spiel wiederholt oder getestet werden.
+
+Sicherer erster Klick:
+    Ist safe_start gesetzt, liegen auf diesem Feld und seinen 8 Nachbarn keine Minen.
+    Der erste Klick deckt damit immer eine 0 auf.
+
+        . . . . .
+        . x x x .      x = garantiert frei
+        . x S x .      S = erster Klick
+        . x x x .
+
+Die Minen werden mit einem teilweisen Fisher-Yates-Shuffle aus den erlaubten Feldern gezogen,
+dadurch wird kein Feld doppelt belegt und es sind keine Wiederholungen nötig.
+*/
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MineAmount {
+    Count(usize),
+    //Anteil der Felder zwischen 0.0 und 1.0, wird auf eine ganze Anzahl gerundet
+    Density(f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoardConfig {
+    pub width: usize,
+    pub height: usize,
+    pub mines: MineAmount,
+    //Erster Klick als (Zeile, Spalte)
+    pub safe_start: Option<(usize, usize)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GenerateError {
+    //Mehr Minen als freie Plätze (nach Abzug des sicheren Bereichs)
+    TooManyMines { mines: usize, available: usize },
+    InvalidDensity(f64),
+    SafeStartOutOfBounds { row: usize, column: usize },
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::TooManyMines { mines, available } => write!(
+                f,
+                "{} Minen passen nicht auf {} freie Felder",
+                mines, available
+            ),
+            GenerateError::InvalidDensity(density) => {
+                write!(f, "Dichte {} liegt nicht zwischen 0 und 1", density)
+            }
+            GenerateError::SafeStartOutOfBounds { row, column } => write!(
+                f,
+                "erster Klick ({}, {}) liegt außerhalb des Minenfelds",
+                row, column
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+impl BoardConfig {
+    pub fn with_count(width: usize, height: usize, mines: usize) -> Self {
+        Self {
+            width,
+            height,
+            mines: MineAmount::Count(mines),
+            safe_start: None,
+        }
+    }
+
+    pub fn with_density(width: usize, height: usize, density: f64) -> Self {
+        Self {
+            width,
+            height,
+            mines: MineAmount::Density(density),
+            safe_start: None,
+        }
+    }
+
+    pub fn safe_start(mut self, row: usize, column: usize) -> Self {
+        self.safe_start = Some((row, column));
+        self
+    }
+
+    //Liegt das Feld im sicheren Bereich um den ersten Klick?
+    fn is_safe(&self, row: usize, column: usize) -> bool {
+        match self.safe_start {
+            Some((start_row, start_column)) => {
+                row.abs_diff(start_row) <= 1 && column.abs_diff(start_column) <= 1
+            }
+            None => false,
+        }
+    }
+
+    fn mine_count(&self) -> Result<usize, GenerateError> {
+        match self.mines {
+            MineAmount::Count(count) => Ok(count),
+            MineAmount::Density(density) if (0.0..=1.0).contains(&density) => {
+                Ok((density * (self.width * self.height) as f64).round() as usize)
+            }
+            MineAmount::Density(density) => Err(GenerateError::InvalidDensity(density)),
+        }
+    }
+}
+
+pub fn generate(config: &BoardConfig, seed: u64) -> Result<Vec<String>, GenerateError> {
+    if let Some((row, column)) = config.safe_start
+        && (row >= config.height || column >= config.width)
+    {
+        return Err(GenerateError::SafeStartOutOfBounds { row, column });
+    }
+
+    let mines = config.mine_count()?;
+
+    //Alle Felder, auf denen eine Mine liegen darf
+    let mut candidates: Vec<(usize, usize)> = (0..config.height)
+        .flat_map(|row| (0..config.width).map(move |column| (row, column)))
+        .filter(|&(row, column)| !config.is_safe(row, column))
+        .collect();
+
+    if mines > candidates.len() {
+        return Err(GenerateError::TooManyMines {
+            mines,
+            available: candidates.len(),
+        });
+    }
+
+    let mut board = vec![vec![NO_MINE; config.width]; config.height];
+    let mut rng = Lcg(seed);
+
+    //Teilweiser Fisher-Yates: die ersten mines Plätze werden zufällig belegt
+    for i in 0..mines {
+        let j = i + rng.below(candidates.len() - i);
+        candidates.swap(i, j);
+        let (row, column) = candidates[i];
+        board[row][column] = MINE;
+    }
+
+    Ok(board
+        .into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Game, GameState};
+
+    fn count_mines(board: &[String]) -> usize {
+        board
+            .iter()
+            .map(|row| row.bytes().filter(|&cell| cell == MINE).count())
+            .sum()
+    }
+
+    #[test]
+    fn same_seed_same_board() {
+        let config = BoardConfig::with_count(9, 9, 10);
+
+        assert_eq!(generate(&config, 7), generate(&config, 7));
+        assert_ne!(generate(&config, 7), generate(&config, 8));
+    }
+
+    #[test]
+    fn board_format_and_mine_count() {
+        let board = generate(&BoardConfig::with_count(8, 5, 12), 1).unwrap();
+
+        assert_eq!(board.len(), 5);
+        assert!(board.iter().all(|row| row.len() == 8));
+        assert!(
+            board
+                .iter()
+                .all(|row| row.bytes().all(|c| c == MINE || c == NO_MINE))
+        );
+        assert_eq!(count_mines(&board), 12);
+    }
+
+    #[test]
+    fn density_is_rounded() {
+        let board = generate(&BoardConfig::with_density(10, 10, 0.155), 3).unwrap();
+        assert_eq!(count_mines(&board), 16);
+
+        let full = generate(&BoardConfig::with_density(3, 2, 1.0), 3).unwrap();
+        assert_eq!(full, vec!["***", "***"]);
+    }
+
+    #[test]
+    fn safe_start_has_no_mines_around() {
+        //Alle Felder außer dem sicheren 3x3 Bereich werden belegt
+        for seed in 0..20 {
+            let config = BoardConfig::with_count(6, 6, 27).safe_start(2, 3);
+            let board = generate(&config, seed).unwrap();
+
+            for row in &board[1..=3] {
+                assert_eq!(&row[2..=4], "   ");
+            }
+            assert_eq!(count_mines(&board), 27);
+        }
+    }
+
+    #[test]
+    fn first_click_never_loses() {
+        for seed in 0..20 {
+            let config = BoardConfig::with_density(9, 9, 0.3).safe_start(0, 0);
+            let board = generate(&config, seed).unwrap();
+            let rows: Vec<&str> = board.iter().map(String::as_str).collect();
+
+            let mut game = Game::new(&rows).unwrap();
+            assert_ne!(game.reveal(0, 0), Ok(GameState::Lost));
+        }
+    }
+
+    #[test]
+    fn invalid_configs() {
+        assert_eq!(
+            generate(&BoardConfig::with_count(3, 3, 10), 0),
+            Err(GenerateError::TooManyMines {
+                mines: 10,
+                available: 9
+            })
+        );
+        assert_eq!(
+            generate(&BoardConfig::with_count(3, 3, 1).safe_start(1, 1), 0),
+            Err(GenerateError::TooManyMines {
+                mines: 1,
+                available: 0
+            })
+        );
+        assert_eq!(
+            generate(&BoardConfig::with_density(3, 3, 1.5), 0),
+            Err(GenerateError::InvalidDensity(1.5))
+        );
+        assert_eq!(
+            generate(&BoardConfig::with_count(3, 3, 1).safe_start(3, 0), 0),
+            Err(GenerateError::SafeStartOutOfBounds { row: 3, column: 0 })
+        );
+    }
+}
@@ -0,0 +1,148 @@
+/*Minesweeper als Bibliothek
+
+Die Kata (annotate) und alles, was darauf aufbaut: das spielbare Spiel, der Generator für
+zufällige Felder und die Solver. main.rs prüft damit die Test Files, andere Programme (Bots,
+Hinweise in einer Oberfläche) benutzen direkt die Bibliothek:
+
+    let rows = generate(&BoardConfig::with_count(9, 9, 10).safe_start(4, 4), seed)?;
+    let mut game = Game::new(&rows.iter().map(String::as_str).collect::<Vec<_>>())?;
+    game.reveal(4, 4)?;
+    let sicher = solve(&game.view());          // sicher ableitbare Felder
+    let tipp = game.safest_guess();            // sonst das Feld mit der kleinsten Minen-Wahrscheinlichkeit
+*/
+pub mod board;
+pub mod game;
+pub mod generator;
+pub mod probability;
+mod rng;
+pub mod solve;
+
+pub use board::{Board, BoardError, parse_board};
+pub use game::Game;
+pub use generator::{BoardConfig, generate};
+pub use probability::mine_probabilities;
+pub use solve::solve;
+
+//Zeichen im Minenfeld
+const MINE: u8 = b'*';
+const NO_MINE: u8 = b' ';
+
+/*
+    Funktionen für das Minesweeper
+*/
+
+pub fn annotate(minefield: &[&str]) -> Result<Vec<String>, BoardError> {
+
+    //Eingabe prüfen, danach ist das Feld rechteckig und enthält nur '*' und ' '
+    let board = parse_board(minefield)?;
+    Ok(annotate_board(&board))
+}
+
+#[allow(clippy::let_and_return)]
+pub(crate) fn annotate_board(board: &Board) -> Vec<String> {
+
+    //Handeling, bei leeren Input
+    if board.is_empty(){
+        return Vec::new();
+    }
+
+    //Umrandung des Minenfeld hinzufügen
+    let minefield_with_spacer = add_spacer_to_minefield(board.cells().to_vec());
+
+    //Minen Berechnen
+    let output_minefield_with_spacer = calculate_mines_count(minefield_with_spacer);
+    
+    //Umrandung wieder entfernen:
+    let output_minefield_without_spacer = remove_spacer_from_minefield(output_minefield_with_spacer);
+
+    output_minefield_without_spacer
+}
+
+fn calculate_mines_count(minefield: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    // Ausgabe-Vektor mit gleichen Maßen, initialisiert mit dem Originalfeld
+    let mut field_with_mine_count = minefield.clone();
+
+    /*
+        ///////
+        /.*.*./
+        /..*../
+        /..*../
+        /...../
+        ///////
+     */
+
+    // Nur im Inneren suchen (kein Rand!)
+    for (i, lines) in minefield.iter().enumerate() {
+        for (j, char) in lines.iter().enumerate() {
+            if *char == NO_MINE {
+                let mut bomb_count = 0;
+
+                for di in -1..=1 {
+                    for dj in -1..=1 {
+                        if di == 0 && dj == 0 {
+                            continue; // skip center
+                        }
+
+                        let ni = i as isize + di;
+                        let nj = j as isize + dj;
+
+                        if minefield[ni as usize][nj as usize] == MINE {
+                            bomb_count += 1;
+                        }
+                    }
+                }
+
+                // Zahl als ASCII-Zeichen (b'0' + bomb_count)
+                if bomb_count != 0{
+                    field_with_mine_count[i][j] = b'0' + bomb_count;
+                }else {
+                    field_with_mine_count[i][j] = b' ';
+                }
+                
+            }
+        }
+    }
+
+    field_with_mine_count
+}
+
+fn add_spacer_to_minefield(minefield: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    let spacer_char: u8 = b'/'; // "/" als ASCII
+    let padding = 1;
+    let minefield_x_len = minefield.len();
+    let minefield_y_len = minefield[0].len();
+    let new_x_len = minefield_x_len + 2 * padding;
+    let new_y_len = minefield_y_len + 2 * padding;
+
+    let mut minefield_with_padding = vec![vec![0u8; new_y_len]; new_x_len];
+
+    for i in 0..new_x_len {
+        for j in 0..new_y_len {
+            // Rahmen setzen
+            if i == 0 || i == new_x_len - 1 || j == 0 || j == new_y_len - 1 {
+                minefield_with_padding[i][j] = spacer_char;
+            } else {
+                // Innenbereich mit Originaldaten befüllen
+                minefield_with_padding[i][j] = minefield[i - 1][j - 1];
+            }
+        }
+    }
+
+    minefield_with_padding
+}
+
+#[allow(clippy::needless_range_loop)]
+fn remove_spacer_from_minefield(minefield: Vec<Vec<u8>>) -> Vec<String> {
+    let x_len = minefield.len();
+    let y_len = minefield[0].len();
+
+    let mut trimmed = vec![];
+    for i in 1..x_len - 1 {
+        // Schneide die Randspalten ab
+        let row_slice = &minefield[i][1..y_len - 1];
+        // Konvertiere zu String (erst &[u8] → String)
+        let row_string = String::from_utf8_lossy(row_slice).to_string();
+        trimmed.push(row_string);
+    }
+    trimmed
+}
@@ -8,6 +8,8 @@ use std::fs::{self, File};
 use std::io::{self, BufRead, BufReader};
 use std::path::Path;
 
+//annotate, Spiel, Generator und Solver liegen in der Bibliothek (lib.rs)
+use minesweeper::{BoardError, annotate};
 
 fn main() -> io::Result<()> {
 
@@ -17,127 +19,6 @@ fn main() -> io::Result<()> {
     
 }
 
-/*
-    Funktionen für das Minesweeper
-*/
-
-fn annotate(minefield: &[&str]) -> Vec<String> {
-
-    // Konvertiere das Minefield in ein Vec<Vec<u8>>.
-    let input_minefield: Vec<Vec<u8>> = minefield
-        .iter()
-        .map(|s| s.as_bytes().to_vec())
-        .collect();
-
-    //Handeling, bei leeren Input
-    if input_minefield.is_empty(){
-        let vec:Vec<String> = Vec::new();
-        return vec;
-    }
-
-    //Umrandung des Minenfeld hinzufügen
-    let minefield_with_spacer = add_spacer_to_minefield(input_minefield);
-
-    //Minen Berechnen
-    let output_minefield_with_spacer = calculate_mines_count(minefield_with_spacer);
-    
-    //Umrandung wieder entfernen:
-    let output_minefield_without_spacer = remove_spacer_from_minefield(output_minefield_with_spacer);
-
-    output_minefield_without_spacer
-}
-
-fn calculate_mines_count(minefield: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
-    let MINE: u8 = b'*';
-    let NO_MINE: u8 = b' ';
-    
-    // Ausgabe-Vektor mit gleichen Maßen, initialisiert mit dem Originalfeld
-    let mut field_with_mine_count = minefield.clone();
-
-    /*
-        ///////
-        /.*.*./
-        /..*../
-        /..*../
-        /...../
-        ///////
-     */
-
-    // Nur im Inneren suchen (kein Rand!)
-    for (i, lines) in minefield.iter().enumerate() {
-        for (j, char) in lines.iter().enumerate() {
-            if *char == NO_MINE {
-                let mut bomb_count = 0;
-
-                for di in -1..=1 {
-                    for dj in -1..=1 {
-                        if di == 0 && dj == 0 {
-                            continue; // skip center
-                        }
-
-                        let ni = i as isize + di;
-                        let nj = j as isize + dj;
-
-                        if minefield[ni as usize][nj as usize] == MINE {
-                            bomb_count += 1;
-                        }
-                    }
-                }
-
-                // Zahl als ASCII-Zeichen (b'0' + bomb_count)
-                if bomb_count != 0{
-                    field_with_mine_count[i][j] = b'0' + bomb_count;
-                }else {
-                    field_with_mine_count[i][j] = b' ';
-                }
-                
-            }
-        }
-    }
-
-    field_with_mine_count
-}
-
-fn add_spacer_to_minefield(minefield: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
-    let spacer_char: u8 = b'/'; // "/" als ASCII
-    let padding = 1;
-    let minefield_x_len = minefield.len();
-    let minefield_y_len = minefield[0].len();
-    let new_x_len = minefield_x_len + 2 * padding;
-    let new_y_len = minefield_y_len + 2 * padding;
-
-    let mut minefield_with_padding = vec![vec![0u8; new_y_len]; new_x_len];
-
-    for i in 0..new_x_len {
-        for j in 0..new_y_len {
-            // Rahmen setzen
-            if i == 0 || i == new_x_len - 1 || j == 0 || j == new_y_len - 1 {
-                minefield_with_padding[i][j] = spacer_char;
-            } else {
-                // Innenbereich mit Originaldaten befüllen
-                minefield_with_padding[i][j] = minefield[i - 1][j - 1];
-            }
-        }
-    }
-
-    minefield_with_padding
-}
-
-fn remove_spacer_from_minefield(minefield: Vec<Vec<u8>>) -> Vec<String> {
-    let x_len = minefield.len();
-    let y_len = minefield[0].len();
-
-    let mut trimmed = vec![];
-    for i in 1..x_len - 1 {
-        // Schneide die Randspalten ab
-        let row_slice = &minefield[i][1..y_len - 1];
-        // Konvertiere zu String (erst &[u8] → String)
-        let row_string = String::from_utf8_lossy(row_slice).to_string();
-        trimmed.push(row_string);
-    }
-    trimmed
-}
-
 /* 
     Funktionen zum Testen aus Datein
 */ 
@@ -158,6 +39,7 @@ fn read_minefiel_from_file(file_path: &Path) -> io::Result<Vec<String>>{
 
 }  
 
+#[allow(clippy::collapsible_if)]
 fn test_files(path: &Path) -> io::Result<Vec<String>> {
     let mut file_names = HashSet::new();
 
@@ -181,7 +63,11 @@ fn test_files(path: &Path) -> io::Result<Vec<String>> {
 
 fn run_tests() -> io::Result<()> {
     let test_dir_path = Path::new("system_test\\test_files");
+    run_tests_in(test_dir_path)
+}
 
+//Gleicher Ablauf für ein beliebiges Verzeichnis, der Test gibt den Pfad selbst vor
+fn run_tests_in(test_dir_path: &Path) -> io::Result<()> {
     // ? entpackt hier ein io::Result<Vec<String>>, wie du richtig erkannt hast
     let test_files = test_files(test_dir_path)?;
 
@@ -198,34 +84,21 @@ fn run_tests() -> io::Result<()> {
 
         let expected = read_minefiel_from_file(&expected_path)?;
 
-        assert_eq!(minefield_calculation(file_minefield), expected);
+        //Ungültige Test Files werden als Fehler beim Lesen gemeldet
+        let actual = minefield_calculation(file_minefield)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        assert_eq!(actual, expected);
     }
 
     Ok(())
 }
 
-fn minefield_calculation(minefield: Vec<String>) -> Vec<String> {
-
-    // Konvertiere das Minefield in ein Vec<Vec<u8>>.
-    let input_minefield: Vec<Vec<u8>> = minefield
-        .iter()
-        .map(|s| s.as_bytes().to_vec())
-        .collect();
-
-    //Handeling, bei leeren Input
-    if input_minefield.is_empty(){
-        let vec:Vec<String> = Vec::new();
-        return vec;
-    }
-
-    //Umrandung des Minenfeld hinzufügen
-    let minefield_with_spacer = add_spacer_to_minefield(input_minefield);
+fn minefield_calculation(minefield: Vec<String>) -> Result<Vec<String>, BoardError> {
 
-    //Minen Berechnen
-    let output_minefield_with_spacer = calculate_mines_count(minefield_with_spacer);
-    
-    //Umrandung wieder entfernen und fertiges Minefeld zurückgeben:
-    remove_spacer_from_minefield(output_minefield_with_spacer)
+    //Gleicher Weg wie annotate, die Zeilen kommen hier nur als String aus der Datei
+    let rows: Vec<&str> = minefield.iter().map(String::as_str).collect();
+    annotate(&rows)
 }
 
 /*
@@ -252,7 +125,7 @@ mod tests {
             " 111 ".to_string(),
         ];
 
-        let result = annotate(&input);
+        let result = annotate(&input).unwrap();
 
         assert_eq!(result, expected);
     }
@@ -273,7 +146,7 @@ mod tests {
             "233333332".to_string(),
         ];
 
-        let result = annotate(&input);
+        let result = annotate(&input).unwrap();
 
         assert_eq!(result, expected);
     }
@@ -292,7 +165,7 @@ mod tests {
         "     ".to_string(),
         ];
 
-        let result = annotate(&input);
+        let result = annotate(&input).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -310,7 +183,7 @@ mod tests {
         "****".to_string(),
         ];
 
-        let result = annotate(&input);
+        let result = annotate(&input).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -324,7 +197,7 @@ mod tests {
         "*2*2*2*2*".to_string(),
         ];
 
-        let result = annotate(&input);
+        let result = annotate(&input).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -342,7 +215,7 @@ mod tests {
         "1".to_string(),
         ];
 
-        let result = annotate(&input);
+        let result = annotate(&input).unwrap();
         assert_eq!(result, expected);
     }
 
@@ -350,9 +223,36 @@ mod tests {
     fn wrong_input(){
         let input = &[];
         let expected: &[&str] = &[];
-        let actual = annotate(input);
+        let actual = annotate(input).unwrap();
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn zero_width_field() {
+        let result = annotate(&["", "", "", ""]).unwrap();
+        assert_eq!(result, vec!["", "", "", ""]);
+    }
+
+    #[test]
+    fn system_test_files() {
+        //Unabhängig vom Arbeitsverzeichnis, in dem cargo test läuft
+        let test_dir_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("system_test")
+            .join("test_files");
+        run_tests_in(&test_dir_path).unwrap();
+    }
+
+    #[test]
+    fn invalid_input_is_rejected() {
+        assert_eq!(
+            annotate(&[" * ", " *"]),
+            Err(BoardError::RaggedRow { line: 2, expected: 3, found: 2 })
+        );
+        assert_eq!(
+            minefield_calculation(vec!["*x".to_string()]),
+            Err(BoardError::InvalidCharacter { line: 1, column: 2, found: 'x' })
+        );
+    }
+
 
 }
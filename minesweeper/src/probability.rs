@@ -0,0 +1,506 @@
+use crate::solve::{Cell, View};
+
+/*Wahrscheinlichkeiten für Minen
+
+Findet der logische Solver (solve) nichts mehr, muss geraten werden. Hier wird für jedes
+verdeckte Feld die Wahrscheinlichkeit berechnet, dass dort eine Mine liegt, damit das
+sicherste Feld gewählt werden kann.
+
+Rand (Frontier) und Inneres:
+    Verdeckte Felder neben einer aufgedeckten Zahl bilden den Rand. Für den Rand werden alle
+    Belegungen aufgezählt, die zu allen Zahlen passen. Die übrigen verdeckten Felder (Inneres)
+    grenzen an keine Zahl, für sie zählt nur, wie viele Minen insgesamt übrig bleiben.
+
+Gewichtung mit der Gesamtzahl der Minen:
+    Eine Randbelegung mit m Minen lässt (Minen - m) Minen für die I inneren Felder übrig.
+    Dafür gibt es C(I, Minen - m) Möglichkeiten, so oft zählt diese Belegung. Belegungen mit
+    wenigen Minen am Rand sind deshalb meist deutlich wahrscheinlicher.
+
+Unabhängige Komponenten:
+    Randfelder, die über keine gemeinsame Zahl verbunden sind, beeinflussen sich nur über die
+    Gesamtzahl der Minen. Jede Komponente wird getrennt aufgezählt, gemerkt wird pro Anzahl k
+    der Minen in der Komponente:
+        - wie viele Belegungen es gibt
+        - wie oft jedes Feld darin eine Mine ist
+    Danach werden die Komponenten über die Anzahl der Minen gefaltet. Statt 2^(a+b) Belegungen
+    werden so nur 2^a + 2^b aufgezählt.
+
+Flaggen zählen wie beim logischen Solver als verdeckt.
+*/
+
+//Mine-Wahrscheinlichkeit pro Feld, None für aufgedeckte Felder und außerhalb des Feldes
+#[derive(Clone, Debug, PartialEq)]
+pub struct Probabilities {
+    cells: Vec<Vec<Option<f64>>>,
+}
+
+impl Probabilities {
+    pub fn get(&self, row: usize, column: usize) -> Option<f64> {
+        self.cells.get(row)?.get(column).copied().flatten()
+    }
+
+    //Verdecktes Feld mit der kleinsten Wahrscheinlichkeit, bei Gleichstand das erste
+    pub fn safest(&self) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize, f64)> = None;
+
+        for (row, cells) in self.cells.iter().enumerate() {
+            for (column, probability) in cells.iter().enumerate() {
+                if let Some(probability) = *probability
+                    && best.is_none_or(|(_, _, lowest)| probability < lowest)
+                {
+                    best = Some((row, column, probability));
+                }
+            }
+        }
+
+        best.map(|(row, column, _)| (row, column))
+    }
+}
+
+//Zahl mit Indizes ihrer verdeckten Nachbarn (in der Komponente)
+struct Constraint {
+    cells: Vec<usize>,
+    number: usize,
+}
+
+struct Component {
+    //Position der Felder im Minenfeld
+    cells: Vec<(usize, usize)>,
+    constraints: Vec<Constraint>,
+}
+
+//Ergebnis der Aufzählung einer Komponente, Index ist jeweils die Anzahl der Minen k
+struct Counts {
+    configurations: Vec<f64>,
+    //cell_mines[k][i]: in wie vielen Belegungen mit k Minen Feld i eine Mine ist
+    cell_mines: Vec<Vec<f64>>,
+}
+
+//Gibt None zurück, wenn es keine Belegung gibt, die zur Ansicht und zur Anzahl der Minen passt
+pub fn mine_probabilities(view: &View, mines: usize) -> Option<Probabilities> {
+    let (components, interior) = split(view);
+    let counts: Vec<Counts> = components.iter().map(enumerate).collect();
+
+    //Verteilung der Minen am ganzen Rand
+    let frontier = counts
+        .iter()
+        .fold(vec![1.0], |total, c| convolve(&total, &c.configurations));
+    let weights = interior_weights(interior.len(), mines, frontier.len());
+
+    let total: f64 = frontier.iter().zip(&weights).map(|(d, w)| d * w).sum();
+    if total == 0.0 {
+        return None;
+    }
+
+    let mut cells = vec![vec![None; view.columns()]; view.rows()];
+
+    for (index, (component, count)) in components.iter().zip(&counts).enumerate() {
+        //Verteilung aller anderen Komponenten
+        let others = counts
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != index)
+            .fold(vec![1.0], |total, (_, c)| {
+                convolve(&total, &c.configurations)
+            });
+
+        for (i, &(row, column)) in component.cells.iter().enumerate() {
+            let mut weight = 0.0;
+            for (k, cell_mines) in count.cell_mines.iter().enumerate() {
+                let rest: f64 = others
+                    .iter()
+                    .enumerate()
+                    .map(|(m, d)| d * weights[k + m])
+                    .sum();
+                weight += cell_mines[i] * rest;
+            }
+            cells[row][column] = Some(weight / total);
+        }
+    }
+
+    if !interior.is_empty() {
+        //Erwartete Anzahl Minen im Inneren, gleichmäßig auf die inneren Felder verteilt
+        let expected: f64 = frontier
+            .iter()
+            .zip(&weights)
+            .enumerate()
+            .map(|(m, (d, w))| d * w * mines.saturating_sub(m) as f64)
+            .sum();
+        let probability = expected / total / interior.len() as f64;
+        for (row, column) in interior {
+            cells[row][column] = Some(probability);
+        }
+    }
+
+    Some(Probabilities { cells })
+}
+
+//Rand in unabhängige Komponenten aufteilen, dazu die inneren Felder
+fn split(view: &View) -> (Vec<Component>, Vec<(usize, usize)>) {
+    //Nummer jedes Randfeldes, zusammen mit den Zahlen (als Liste von Randfeld-Nummern)
+    let mut index = vec![vec![None; view.columns()]; view.rows()];
+    let mut frontier = Vec::new();
+    let mut constraints = Vec::new();
+
+    for row in 0..view.rows() {
+        for column in 0..view.columns() {
+            let Cell::Number(number) = view.cell(row, column) else {
+                continue;
+            };
+
+            let mut cells = Vec::new();
+            for (r, c) in view.neighbours(row, column) {
+                if view.cell(r, c) != Cell::Hidden {
+                    continue;
+                }
+                let i = *index[r][c].get_or_insert_with(|| {
+                    frontier.push((r, c));
+                    frontier.len() - 1
+                });
+                cells.push(i);
+            }
+            constraints.push(Constraint {
+                cells,
+                number: number as usize,
+            });
+        }
+    }
+
+    //Union-Find: Felder derselben Zahl gehören zur selben Komponente
+    let mut parent: Vec<usize> = (0..frontier.len()).collect();
+    for constraint in &constraints {
+        for pair in constraint.cells.windows(2) {
+            let a = find(&mut parent, pair[0]);
+            let b = find(&mut parent, pair[1]);
+            parent[a] = b;
+        }
+    }
+
+    //Felder und Zahlen den Komponenten zuordnen, Reihenfolge wie beim Einlesen
+    let mut component_of = vec![None; frontier.len()];
+    let mut local = vec![0; frontier.len()];
+    let mut components: Vec<Component> = Vec::new();
+    for (i, &position) in frontier.iter().enumerate() {
+        let root = find(&mut parent, i);
+        let id = *component_of[root].get_or_insert_with(|| {
+            components.push(Component {
+                cells: Vec::new(),
+                constraints: Vec::new(),
+            });
+            components.len() - 1
+        });
+        local[i] = components[id].cells.len();
+        components[id].cells.push(position);
+    }
+
+    for constraint in constraints {
+        //Zahl ohne verdeckte Nachbarn: muss 0 sein, sonst passt keine Belegung
+        let Some(&first) = constraint.cells.first() else {
+            if constraint.number > 0 {
+                components.push(Component {
+                    cells: Vec::new(),
+                    constraints: vec![constraint],
+                });
+            }
+            continue;
+        };
+        let id = component_of[find(&mut parent, first)].unwrap();
+        components[id].constraints.push(Constraint {
+            cells: constraint.cells.iter().map(|&i| local[i]).collect(),
+            number: constraint.number,
+        });
+    }
+
+    let interior = (0..view.rows())
+        .flat_map(|row| (0..view.columns()).map(move |column| (row, column)))
+        .filter(|&(row, column)| {
+            view.cell(row, column) == Cell::Hidden && index[row][column].is_none()
+        })
+        .collect();
+
+    (components, interior)
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+//Alle passenden Belegungen einer Komponente aufzählen (Backtracking)
+fn enumerate(component: &Component) -> Counts {
+    let n = component.cells.len();
+    let mut search = Search {
+        component,
+        cell_constraints: vec![Vec::new(); n],
+        mines: vec![0; component.constraints.len()],
+        open: component
+            .constraints
+            .iter()
+            .map(|c| c.cells.len())
+            .collect(),
+        assignment: vec![false; n],
+        counts: Counts {
+            configurations: vec![0.0; n + 1],
+            cell_mines: vec![vec![0.0; n]; n + 1],
+        },
+    };
+    for (id, constraint) in component.constraints.iter().enumerate() {
+        for &cell in &constraint.cells {
+            search.cell_constraints[cell].push(id);
+        }
+    }
+
+    //Zahl ohne verdeckte Nachbarn (siehe split): keine gültige Belegung
+    if search
+        .open
+        .iter()
+        .zip(&component.constraints)
+        .any(|(&open, c)| open == 0 && c.number > 0)
+    {
+        return search.counts;
+    }
+
+    search.assign(0, 0);
+    search.counts
+}
+
+struct Search<'a> {
+    component: &'a Component,
+    cell_constraints: Vec<Vec<usize>>,
+    //Pro Zahl: bisher gesetzte Minen und noch nicht belegte Felder
+    mines: Vec<usize>,
+    open: Vec<usize>,
+    assignment: Vec<bool>,
+    counts: Counts,
+}
+
+impl Search<'_> {
+    fn assign(&mut self, cell: usize, placed: usize) {
+        if cell == self.assignment.len() {
+            self.counts.configurations[placed] += 1.0;
+            for (i, &mine) in self.assignment.iter().enumerate() {
+                if mine {
+                    self.counts.cell_mines[placed][i] += 1.0;
+                }
+            }
+            return;
+        }
+
+        for mine in [false, true] {
+            if !self.fits(cell, mine) {
+                continue;
+            }
+            self.set(cell, mine, true);
+            self.assign(cell + 1, placed + mine as usize);
+            self.set(cell, mine, false);
+        }
+    }
+
+    //Bleibt jede Zahl um das Feld erfüllbar, wenn es so belegt wird?
+    fn fits(&self, cell: usize, mine: bool) -> bool {
+        self.cell_constraints[cell].iter().all(|&id| {
+            let mines = self.mines[id] + mine as usize;
+            let number = self.component.constraints[id].number;
+            //Das Feld selbst ist noch in open enthalten, danach bleiben open - 1 Felder
+            mines <= number && mines + self.open[id] > number
+        })
+    }
+
+    fn set(&mut self, cell: usize, mine: bool, apply: bool) {
+        self.assignment[cell] = apply && mine;
+        for &id in &self.cell_constraints[cell] {
+            if apply {
+                self.open[id] -= 1;
+                self.mines[id] += mine as usize;
+            } else {
+                self.open[id] += 1;
+                self.mines[id] -= mine as usize;
+            }
+        }
+    }
+}
+
+fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0.0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
+
+//weights[m] ist proportional zu C(interior, mines - m), also den Möglichkeiten, die restlichen
+//Minen im Inneren zu verteilen. Skaliert auf das größte Gewicht, damit f64 nicht überläuft.
+fn interior_weights(interior: usize, mines: usize, len: usize) -> Vec<f64> {
+    let logs: Vec<Option<f64>> = (0..len)
+        .map(|m| {
+            let rest = mines.checked_sub(m)?;
+            (rest <= interior).then(|| ln_binomial(interior, rest))
+        })
+        .collect();
+    let max = logs
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    logs.iter()
+        .map(|log| log.map_or(0.0, |log| (log - max).exp()))
+        .collect()
+}
+
+fn ln_binomial(n: usize, k: usize) -> f64 {
+    let k = k.min(n - k);
+    (1..=k).map(|i| ((n - k + i) as f64 / i as f64).ln()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::parse_board;
+    use crate::game::Game;
+    use crate::generator::{BoardConfig, generate};
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "erwartet {}, bekommen {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn fifty_fifty() {
+        let probabilities = mine_probabilities(&View::parse(&["..", "11"]), 1).unwrap();
+
+        assert_close(probabilities.get(0, 0), 0.5);
+        assert_close(probabilities.get(0, 1), 0.5);
+        assert_eq!(probabilities.get(1, 0), None);
+    }
+
+    #[test]
+    fn certain_cells() {
+        let probabilities = mine_probabilities(&View::parse(&[".1.", "11."]), 1).unwrap();
+
+        assert_close(probabilities.get(0, 0), 1.0);
+        assert_close(probabilities.get(0, 2), 0.0);
+        assert_close(probabilities.get(1, 2), 0.0);
+        assert_eq!(probabilities.safest(), Some((0, 2)));
+    }
+
+    #[test]
+    fn global_mine_count_weighting() {
+        //Die 1 hat drei verdeckte Nachbarn, (0, 2) und (1, 2) gehören zum Inneren
+        let field = View::parse(&["1..", "..."]);
+
+        let one = mine_probabilities(&field, 1).unwrap();
+        assert_close(one.get(0, 1), 1.0 / 3.0);
+        assert_close(one.get(0, 2), 0.0);
+
+        let two = mine_probabilities(&field, 2).unwrap();
+        assert_close(two.get(1, 1), 1.0 / 3.0);
+        assert_close(two.get(1, 2), 0.5);
+        assert_eq!(two.safest(), Some((0, 1)));
+    }
+
+    #[test]
+    fn independent_components() {
+        let field = View::parse(&["..", "11", "  ", "11", ".."]);
+
+        let (components, interior) = split(&field);
+        assert_eq!(components.len(), 2);
+        assert!(interior.is_empty());
+
+        let probabilities = mine_probabilities(&field, 2).unwrap();
+        assert_close(probabilities.get(0, 1), 0.5);
+        assert_close(probabilities.get(4, 0), 0.5);
+
+        //Jede Komponente braucht genau eine Mine
+        assert_eq!(mine_probabilities(&field, 3), None);
+        assert_eq!(mine_probabilities(&field, 1), None);
+    }
+
+    #[test]
+    fn inconsistent_view() {
+        assert_eq!(mine_probabilities(&View::parse(&["2.", ".."]), 5), None);
+        assert_eq!(mine_probabilities(&View::parse(&["1 "]), 0), None);
+    }
+
+    //Vergleich mit dem Durchprobieren aller Verteilungen der Minen auf die verdeckten Felder
+    fn brute_force(view: &View, mines: usize) -> Vec<Vec<Option<f64>>> {
+        let hidden: Vec<(usize, usize)> = (0..view.rows())
+            .flat_map(|row| (0..view.columns()).map(move |column| (row, column)))
+            .filter(|&(row, column)| view.cell(row, column) == Cell::Hidden)
+            .collect();
+
+        let mut valid = 0.0;
+        let mut counts = vec![0.0; hidden.len()];
+        for mask in 0u32..1 << hidden.len() {
+            if mask.count_ones() as usize != mines {
+                continue;
+            }
+            let is_mine = |r: usize, c: usize| {
+                hidden
+                    .iter()
+                    .position(|&p| p == (r, c))
+                    .is_some_and(|i| mask & (1 << i) != 0)
+            };
+            let consistent = (0..view.rows()).all(|row| {
+                (0..view.columns()).all(|column| match view.cell(row, column) {
+                    Cell::Number(n) => {
+                        view.neighbours(row, column)
+                            .filter(|&(r, c)| is_mine(r, c))
+                            .count()
+                            == n as usize
+                    }
+                    Cell::Hidden => true,
+                })
+            });
+            if consistent {
+                valid += 1.0;
+                for (i, count) in counts.iter_mut().enumerate() {
+                    if mask & (1 << i) != 0 {
+                        *count += 1.0;
+                    }
+                }
+            }
+        }
+
+        let mut cells = vec![vec![None; view.columns()]; view.rows()];
+        for (&(row, column), count) in hidden.iter().zip(counts) {
+            cells[row][column] = Some(count / valid);
+        }
+        cells
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        for seed in 0..15 {
+            let config = BoardConfig::with_count(5, 4, 5).safe_start(0, 0);
+            let rows = generate(&config, seed).unwrap();
+            let rows: Vec<&str> = rows.iter().map(String::as_str).collect();
+            let board = parse_board(&rows).unwrap();
+
+            let mut game = Game::from_board(&board);
+            game.reveal(0, 0).unwrap();
+            let field = game.view();
+
+            let expected = brute_force(&field, board.mine_count());
+            let actual = mine_probabilities(&field, board.mine_count()).unwrap();
+            for (row, cells) in expected.iter().enumerate() {
+                for (column, &probability) in cells.iter().enumerate() {
+                    match probability {
+                        Some(p) => assert_close(actual.get(row, column), p),
+                        None => assert_eq!(actual.get(row, column), None),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//Einfacher Pseudo-Zufallsgenerator (LCG), damit keine externen Crates gebraucht werden.
+//Gleicher Startwert (Seed) -> gleiche Folge, dadurch sind generierte Minenfelder reproduzierbar.
+pub(crate) struct Lcg(pub(crate) u64);
+
+impl Lcg {
+    pub(crate) fn next(&mut self) -> u32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) as u32
+    }
+
+    //Zufällige Zahl in 0..bound (bound > 0). Der kleine Modulo-Fehler spielt hier keine Rolle.
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        self.next() as usize % bound
+    }
+}
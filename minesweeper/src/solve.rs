@@ -0,0 +1,458 @@
+use std::fmt;
+
+use crate::board::{self, Board};
+use crate::game::{Game, GameState};
+
+/*Logischer Solver
+
+Bekommt den sichtbaren Zustand eines Spiels (View) und leitet daraus Felder ab, die sicher frei
+oder sicher eine Mine sind. Es wird nur benutzt, was der Spieler sieht: aufgedeckte Zahlen und
+verdeckte Felder. Flaggen zählen als verdeckt, da sie falsch gesetzt sein können.
+
+Jede aufgedeckte Zahl ist eine Bedingung: unter ihren noch offenen Nachbarn liegen genau
+(Zahl - bereits abgeleitete Minen) Minen.
+
+1. Einzelne Zahl:
+    - fehlen keine Minen mehr -> alle offenen Nachbarn sind sicher
+    - fehlen genau so viele Minen wie offene Nachbarn da sind -> alle sind Minen
+
+2. Teilmenge zweier Zahlen:
+    Liegen alle offenen Nachbarn von A auch um B, dann enthalten die restlichen Nachbarn von B
+    genau (Minen B - Minen A) Minen. Darauf wird wieder Regel 1 angewendet:
+
+        . . .       A = 1 bei (1, 0): eine Mine in {(0,0), (0,1)}
+        1 2 1       B = 2 bei (1, 1): zwei Minen in {(0,0), (0,1), (0,2)}
+                    -> (0,2) enthält 2 - 1 = 1 Mine
+
+Gefundene Felder werden als bekannt eingetragen und es wird erneut gesucht, bis sich nichts
+mehr ändert. Jede Ableitung enthält den Grund (Reason) und kann als Text ausgegeben werden.
+*/
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Hidden,
+    //Aufgedeckte Zahl, 0 für ein leeres Feld
+    Number(u8),
+}
+
+//Sichtbarer Zustand des Minenfelds
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct View {
+    cells: Vec<Vec<Cell>>,
+}
+
+impl View {
+    pub(crate) fn new(cells: Vec<Vec<Cell>>) -> Self {
+        Self { cells }
+    }
+
+    //Ansicht im Format von Game::render: '.' und 'F' verdeckt, ' ' oder Ziffer aufgedeckt
+    #[cfg(test)]
+    pub(crate) fn parse(rows: &[&str]) -> Self {
+        Self::new(
+            rows.iter()
+                .map(|row| {
+                    row.chars()
+                        .map(|c| match c {
+                            '.' | 'F' => Cell::Hidden,
+                            ' ' => Cell::Number(0),
+                            digit => Cell::Number(digit.to_digit(10).unwrap() as u8),
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.cells.first().map_or(0, |row| row.len())
+    }
+
+    pub fn cell(&self, row: usize, column: usize) -> Cell {
+        self.cells[row][column]
+    }
+
+    pub(crate) fn neighbours(
+        &self,
+        row: usize,
+        column: usize,
+    ) -> impl Iterator<Item = (usize, usize)> {
+        board::neighbours(self.rows(), self.columns(), row, column)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    Mine,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    //Regel 1 an der Zahl bei cell
+    Single {
+        cell: (usize, usize),
+        number: u8,
+    },
+    //Regel 2: die offenen Nachbarn von inner liegen alle um outer,
+    //die übrigen Nachbarn von outer enthalten mines Minen
+    Subset {
+        inner: (usize, usize),
+        outer: (usize, usize),
+        mines: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deduction {
+    pub row: usize,
+    pub column: usize,
+    pub verdict: Verdict,
+    pub reason: Reason,
+}
+
+impl fmt::Display for Deduction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = match self.verdict {
+            Verdict::Safe => "ist sicher",
+            Verdict::Mine => "ist eine Mine",
+        };
+        write!(f, "({}, {}) {}: ", self.row, self.column, verdict)?;
+
+        match (self.reason, self.verdict) {
+            (Reason::Single { cell, number }, Verdict::Safe) => write!(
+                f,
+                "um die {} bei ({}, {}) liegen schon alle Minen",
+                number, cell.0, cell.1
+            ),
+            (Reason::Single { cell, number }, Verdict::Mine) => write!(
+                f,
+                "die {} bei ({}, {}) hat nur noch so viele offene Nachbarn wie fehlende Minen",
+                number, cell.0, cell.1
+            ),
+            (
+                Reason::Subset {
+                    inner,
+                    outer,
+                    mines,
+                },
+                _,
+            ) => write!(
+                f,
+                "die offenen Nachbarn von ({}, {}) liegen alle um ({}, {}), \
+                 die übrigen Nachbarn von ({}, {}) enthalten {} Minen",
+                inner.0, inner.1, outer.0, outer.1, outer.0, outer.1, mines
+            ),
+        }
+    }
+}
+
+//Bedingung einer aufgedeckten Zahl
+struct Constraint {
+    cell: (usize, usize),
+    number: u8,
+    //Offene Nachbarn (verdeckt und noch nicht abgeleitet), sortiert
+    unknown: Vec<(usize, usize)>,
+    //Minen, die unter unknown noch fehlen
+    mines: usize,
+}
+
+type Known = Vec<Vec<Option<Verdict>>>;
+
+pub fn solve(view: &View) -> Vec<Deduction> {
+    let mut known: Known = vec![vec![None; view.columns()]; view.rows()];
+    let mut deductions = Vec::new();
+
+    loop {
+        let constraints = constraints(view, &known);
+
+        let mut found = Vec::new();
+        for constraint in &constraints {
+            single(constraint, &mut found);
+        }
+        //Die teurere Regel nur, wenn die einfache nichts mehr findet
+        if found.is_empty() {
+            for inner in &constraints {
+                for outer in &constraints {
+                    subset(inner, outer, &mut found);
+                }
+            }
+        }
+
+        let mut progress = false;
+        for deduction in found {
+            let entry = &mut known[deduction.row][deduction.column];
+            if entry.is_none() {
+                *entry = Some(deduction.verdict);
+                deductions.push(deduction);
+                progress = true;
+            }
+        }
+        if !progress {
+            return deductions;
+        }
+    }
+}
+
+fn constraints(view: &View, known: &Known) -> Vec<Constraint> {
+    let mut constraints = Vec::new();
+
+    for row in 0..view.rows() {
+        for column in 0..view.columns() {
+            let Cell::Number(number) = view.cell(row, column) else {
+                continue;
+            };
+
+            let mut unknown = Vec::new();
+            let mut known_mines = 0;
+            for (r, c) in view.neighbours(row, column) {
+                if view.cell(r, c) != Cell::Hidden {
+                    continue;
+                }
+                match known[r][c] {
+                    Some(Verdict::Mine) => known_mines += 1,
+                    Some(Verdict::Safe) => {}
+                    None => unknown.push((r, c)),
+                }
+            }
+
+            if !unknown.is_empty() {
+                constraints.push(Constraint {
+                    cell: (row, column),
+                    number,
+                    unknown,
+                    //Bei widersprüchlicher Ansicht nicht unter 0 fallen
+                    mines: (number as usize).saturating_sub(known_mines),
+                });
+            }
+        }
+    }
+
+    constraints
+}
+
+//Regel 1
+fn single(constraint: &Constraint, found: &mut Vec<Deduction>) {
+    let verdict = if constraint.mines == 0 {
+        Verdict::Safe
+    } else if constraint.mines == constraint.unknown.len() {
+        Verdict::Mine
+    } else {
+        return;
+    };
+
+    let reason = Reason::Single {
+        cell: constraint.cell,
+        number: constraint.number,
+    };
+    push_all(&constraint.unknown, verdict, reason, found);
+}
+
+//Regel 2
+fn subset(inner: &Constraint, outer: &Constraint, found: &mut Vec<Deduction>) {
+    //Nur Zahlen, die sich Nachbarn teilen können
+    let close =
+        inner.cell.0.abs_diff(outer.cell.0) <= 2 && inner.cell.1.abs_diff(outer.cell.1) <= 2;
+    if !close || inner.cell == outer.cell || inner.unknown.len() >= outer.unknown.len() {
+        return;
+    }
+    if !inner
+        .unknown
+        .iter()
+        .all(|cell| outer.unknown.binary_search(cell).is_ok())
+    {
+        return;
+    }
+    let Some(mines) = outer.mines.checked_sub(inner.mines) else {
+        return;
+    };
+
+    let rest: Vec<(usize, usize)> = outer
+        .unknown
+        .iter()
+        .filter(|cell| inner.unknown.binary_search(cell).is_err())
+        .copied()
+        .collect();
+    let verdict = if mines == 0 {
+        Verdict::Safe
+    } else if mines == rest.len() {
+        Verdict::Mine
+    } else {
+        return;
+    };
+
+    let reason = Reason::Subset {
+        inner: inner.cell,
+        outer: outer.cell,
+        mines,
+    };
+    push_all(&rest, verdict, reason, found);
+}
+
+fn push_all(
+    cells: &[(usize, usize)],
+    verdict: Verdict,
+    reason: Reason,
+    found: &mut Vec<Deduction>,
+) {
+    found.extend(cells.iter().map(|&(row, column)| Deduction {
+        row,
+        column,
+        verdict,
+        reason,
+    }));
+}
+
+//Kann das Minenfeld ab dem ersten Klick nur mit Logik (ohne Raten) gelöst werden?
+pub fn solvable(board: &Board, row: usize, column: usize) -> bool {
+    let mut game = Game::from_board(board);
+    if game.reveal(row, column).is_err() {
+        return false;
+    }
+
+    while game.state() == GameState::Running {
+        let safe: Vec<Deduction> = solve(&game.view())
+            .into_iter()
+            .filter(|deduction| deduction.verdict == Verdict::Safe)
+            .collect();
+        if safe.is_empty() {
+            return false;
+        }
+        for deduction in safe {
+            //Felder können schon durch einen Flood-Fill aufgedeckt sein
+            let _ = game.reveal(deduction.row, deduction.column);
+        }
+    }
+
+    game.state() == GameState::Won
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::parse_board;
+
+    fn cells(deductions: &[Deduction]) -> Vec<(usize, usize, Verdict)> {
+        deductions
+            .iter()
+            .map(|d| (d.row, d.column, d.verdict))
+            .collect()
+    }
+
+    #[test]
+    fn single_mine() {
+        let deductions = solve(&View::parse(&[".1", "11"]));
+
+        assert_eq!(
+            deductions,
+            vec![Deduction {
+                row: 0,
+                column: 0,
+                verdict: Verdict::Mine,
+                reason: Reason::Single {
+                    cell: (0, 1),
+                    number: 1
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn known_mine_makes_neighbours_safe() {
+        let deductions = solve(&View::parse(&[".1.", "11."]));
+
+        assert_eq!(
+            cells(&deductions),
+            vec![
+                (0, 0, Verdict::Mine),
+                (0, 2, Verdict::Safe),
+                (1, 2, Verdict::Safe)
+            ]
+        );
+        assert_eq!(
+            deductions[1].reason,
+            Reason::Single {
+                cell: (0, 1),
+                number: 1
+            }
+        );
+    }
+
+    #[test]
+    fn subset_reasoning() {
+        let deductions = solve(&View::parse(&["...", "121"]));
+
+        assert_eq!(
+            cells(&deductions),
+            vec![
+                (0, 2, Verdict::Mine),
+                (0, 0, Verdict::Mine),
+                (0, 1, Verdict::Safe)
+            ]
+        );
+        assert_eq!(
+            deductions[0].reason,
+            Reason::Subset {
+                inner: (1, 0),
+                outer: (1, 1),
+                mines: 1
+            }
+        );
+    }
+
+    #[test]
+    fn explanations() {
+        let deductions = solve(&View::parse(&["...", "121"]));
+
+        assert_eq!(
+            deductions[0].to_string(),
+            "(0, 2) ist eine Mine: die offenen Nachbarn von (1, 0) liegen alle um (1, 1), \
+             die übrigen Nachbarn von (1, 1) enthalten 1 Minen"
+        );
+        assert_eq!(
+            deductions[2].to_string(),
+            "(0, 1) ist sicher: um die 1 bei (1, 0) liegen schon alle Minen"
+        );
+    }
+
+    #[test]
+    fn no_deduction_without_information() {
+        assert!(solve(&View::parse(&["..", ".1"])).is_empty());
+        assert!(solve(&View::parse(&["...", "..."])).is_empty());
+        //Flaggen werden nicht als Minen übernommen
+        assert!(solve(&View::parse(&["F.", ".1"])).is_empty());
+    }
+
+    #[test]
+    fn solve_game_view() {
+        let mut game = Game::new(&["     ", "     ", " *   ", "     "]).unwrap();
+        game.reveal(0, 4).unwrap();
+
+        let deductions = solve(&game.view());
+        assert!(deductions.contains(&Deduction {
+            row: 2,
+            column: 1,
+            verdict: Verdict::Mine,
+            reason: Reason::Single {
+                cell: (1, 2),
+                number: 1
+            },
+        }));
+    }
+
+    #[test]
+    fn solvable_boards() {
+        let board = parse_board(&["     ", "     ", " *   ", "     "]).unwrap();
+        assert!(solvable(&board, 0, 4));
+
+        //Nach dem Klick bleiben drei gleichwertige Felder übrig
+        let board = parse_board(&["* ", "  "]).unwrap();
+        assert!(!solvable(&board, 1, 1));
+        //Klick auf eine Mine
+        assert!(!solvable(&board, 0, 0));
+    }
+}